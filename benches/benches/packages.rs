@@ -0,0 +1,32 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use deb::control::{archive::Package, de};
+use std::io::{BufReader, Cursor};
+
+// 1000 paragraphs sampled evenly from bookworm's main/binary-amd64
+// Packages index.
+const PACKAGES: &str = include_str!("../fixtures/Packages");
+const PACKAGES_COUNT: usize = 1000;
+
+fn parse_packages() -> Vec<Package> {
+    let mut reader = BufReader::new(Cursor::new(PACKAGES));
+    de::from_reader_iter::<Package, _>(&mut reader)
+        .map(|package| package.unwrap())
+        .collect()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    assert_eq!(PACKAGES_COUNT, parse_packages().len());
+
+    let mut group = c.benchmark_group("archive_packages");
+
+    group.throughput(Throughput::Elements(PACKAGES_COUNT as u64));
+    group.bench_function("paragraphs", |b| b.iter(parse_packages));
+
+    group.throughput(Throughput::Bytes(PACKAGES.len() as u64));
+    group.bench_function("bytes", |b| b.iter(parse_packages));
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
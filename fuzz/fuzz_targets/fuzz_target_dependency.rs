@@ -4,5 +4,12 @@ use deb::dependency::Dependency;
 use libfuzzer_sys::fuzz_target;
 
 fuzz_target!(|data: &str| {
-    let _ = data.parse::<Dependency>();
+    let Ok(dep) = data.parse::<Dependency>() else {
+        return;
+    };
+    let encoded = dep.to_string();
+    let dep2: Dependency = encoded
+        .parse()
+        .unwrap_or_else(|e| panic!("{encoded:?} (from {data:?}) failed to reparse: {e:?}"));
+    assert_eq!(dep, dep2, "{data:?} didn't round trip via {encoded:?}");
 });
@@ -250,18 +250,29 @@ impl std::fmt::Display for Architecture {
             }
         }
 
-        write!(
-            f,
-            "{}",
-            match_tuple_to_parts!([
-                self.abi.as_ref(),
-                self.libc.as_ref(),
-                self.os.as_ref(),
-                self.cpu.as_ref()
-            ])
-            .join("-")
-            .trim_start_matches("any-")
-        )
+        let tuple = [
+            self.abi.as_ref(),
+            self.libc.as_ref(),
+            self.os.as_ref(),
+            self.cpu.as_ref(),
+        ];
+
+        // Wildcards fill in missing leading parts with `any` rather than the
+        // `base-gnu-linux` defaults, so they can't use the short names.
+        let mut parts = if tuple.contains(&"any") {
+            tuple.to_vec()
+        } else {
+            match_tuple_to_parts!(tuple)
+        };
+
+        // Leading `any` parts can be dropped, but only as long as what's
+        // left is still parsed as a wildcard -- `any-any-any-armhf` is
+        // `any-armhf`, not `armhf`.
+        while parts.len() > 1 && parts[0] == "any" && parts[1..].join("-").contains("any") {
+            parts.remove(0);
+        }
+
+        write!(f, "{}", parts.join("-"))
     }
 }
 
@@ -322,6 +333,12 @@ impl FromStr for Architecture {
             return Err(Error::Malformed);
         }
 
+        // something like `linux-` or `-amd64` would otherwise parse with
+        // an empty part, which is then lost when written back out.
+        if tuple.split('-').any(str::is_empty) {
+            return Err(Error::Malformed);
+        }
+
         if tuple.contains("any") {
             return Self::wildcard_from_str(tuple);
         }
@@ -373,6 +390,17 @@ $(
         if self == &$arch {
             return Some($tuple);
         }
+)*
+        None
+    }
+
+    /// Return the Debian [Architecture] for the [multiarch::Tuple], or
+    /// `None` if no known [Architecture] uses that [multiarch::Tuple].
+    pub fn from_multiarch_tuple(tuple: &multiarch::Tuple) -> Option<Architecture> {
+$(
+        if tuple == &$tuple {
+            return Some($arch);
+        }
 )*
         None
     }
@@ -390,9 +418,11 @@ $(
         let a1: Architecture = $str.parse().unwrap();
         assert_eq!($arch, a1);
 
-        // Check a round trip to a string works.
+        // Check a round trip to a string works, and that the string is
+        // the canonical name.
         let a2: Architecture = $arch.to_string().parse().unwrap();
         assert_eq!($arch, a2);
+        assert_eq!($str, $arch.to_string());
     }
 )*
     };
@@ -782,6 +812,95 @@ impl Architecture {
     pub fn is_special(&self) -> bool {
         *self == SOURCE || *self == ALL || *self == ANY || *self == NATIVE || self.is_wildcard()
     }
+
+    /// Return the operating system kernel of this [Architecture], such as
+    /// `linux`, `hurd` or `kfreebsd`. The special [SOURCE], [ALL] and
+    /// [NATIVE] architectures have no kernel, and will return an empty
+    /// string.
+    pub fn kernel(&self) -> &str {
+        &self.os
+    }
+
+    /// Return `true` if this is a concrete [Architecture] which packages
+    /// can actually be built for, such as [AMD64] -- that is, neither a
+    /// wildcard (like `linux-any`) nor special (like [ALL] or [SOURCE]).
+    pub fn is_concrete(&self) -> bool {
+        !self.is_wildcard() && !self.is_special()
+    }
+
+    /// Return `true` if this is a concrete (not a wildcard or special)
+    /// [Architecture] targeting the Linux kernel, such as [AMD64] or
+    /// [ARMHF]. This is `false` for `hurd-*` or `kfreebsd-*` arches, as well
+    /// as wildcards like `linux-any`.
+    pub fn is_any_linux(&self) -> bool {
+        self.os == "linux" && !self.is_wildcard()
+    }
+
+    /// Return `true` if this [Architecture] uses the GNU libc userland, which
+    /// is the case for most Debian ports (including [HURD_I386] and
+    /// [KFREEBSD_AMD64]), but not for the `musl` or `uclibc` variants.
+    pub fn is_gnu_userland(&self) -> bool {
+        self.libc == "gnu"
+    }
+
+    /// Check to see if two [Architecture]s run on the same operating system
+    /// kernel. For instance, [AMD64] and [I386] are both `linux`, but
+    /// [KFREEBSD_AMD64] is `kfreebsd`.
+    ///
+    /// This is only meaningful for concrete architectures (see
+    /// [Architecture::is_concrete]), so it will always return `false` if
+    /// either is a wildcard (such as `linux-any` or [ANY]), or special
+    /// (such as [SOURCE], [ALL] or [NATIVE]).
+    pub fn same_kernel(&self, other: &Architecture) -> bool {
+        if !self.is_concrete() || !other.is_concrete() {
+            return false;
+        }
+        self.os == other.os
+    }
+}
+
+/// Parse a GNU triplet, such as `aarch64-linux-gnu` or
+/// `x86_64-pc-linux-gnu`, as used to name cross-toolchains, and return the
+/// Debian [Architecture] it targets.
+///
+/// The optional vendor field (such as `pc` or `unknown`) is ignored, and
+/// `i486` through `i686` CPUs are treated as `i386`. Triplets without a
+/// matching Debian [Architecture] (such as `x86_64-w64-mingw32` or a bare
+/// `mingw32`) will return `None`.
+///
+/// ```
+/// use deb::architecture;
+///
+/// assert_eq!(
+///     Some(architecture::ARM64),
+///     architecture::from_gnu_triplet("aarch64-linux-gnu"),
+/// );
+/// assert_eq!(None, architecture::from_gnu_triplet("x86_64-w64-mingw32"));
+/// ```
+pub fn from_gnu_triplet(triplet: &str) -> Option<Architecture> {
+    let chunks: Vec<&str> = triplet.split('-').collect();
+    let (cpu, system) = match chunks[..] {
+        [cpu, _vendor, os, abi] => (cpu, vec![os, abi]),
+        // Without a vendor, the middle of the triplet is a known kernel.
+        [cpu, kernel, abi]
+            if kernel
+                .parse::<multiarch::SyscallAbi>()
+                .is_ok_and(|kernel| !matches!(kernel, multiarch::SyscallAbi::Other(_))) =>
+        {
+            (cpu, vec![kernel, abi])
+        }
+        [cpu, _vendor, os] => (cpu, vec![os]),
+        [cpu, os] => (cpu, vec![os]),
+        _ => return None,
+    };
+
+    let cpu = match cpu {
+        "i486" | "i586" | "i686" => "i386",
+        cpu => cpu,
+    };
+
+    let tuple: multiarch::Tuple = format!("{}-{}", cpu, system.join("-")).parse().ok()?;
+    Architecture::from_multiarch_tuple(&tuple)
 }
 
 #[cfg(test)]
@@ -817,6 +936,113 @@ mod test {
         };
     }
 
+    macro_rules! check_same_kernel {
+        ($name:ident, $left:expr, $right:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!($expected, $left.same_kernel(&$right));
+                assert_eq!($expected, $right.same_kernel(&$left));
+            }
+        };
+    }
+
+    macro_rules! check_from_gnu_triplet {
+        ($name:ident, $triplet:expr, $arch:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!($arch, from_gnu_triplet($triplet));
+            }
+        };
+    }
+
+    check_from_gnu_triplet!(gnu_triplet_arm64, "aarch64-linux-gnu", Some(ARM64));
+    check_from_gnu_triplet!(gnu_triplet_amd64, "x86_64-linux-gnu", Some(AMD64));
+    check_from_gnu_triplet!(gnu_triplet_amd64_vendor, "x86_64-pc-linux-gnu", Some(AMD64));
+    check_from_gnu_triplet!(gnu_triplet_i686, "i686-linux-gnu", Some(I386));
+    check_from_gnu_triplet!(gnu_triplet_armhf, "arm-linux-gnueabihf", Some(ARMHF));
+    check_from_gnu_triplet!(
+        gnu_triplet_armel_vendor,
+        "arm-unknown-linux-gnueabi",
+        Some(ARMEL)
+    );
+    check_from_gnu_triplet!(gnu_triplet_s390x, "s390x-linux-gnu", Some(S390X));
+    check_from_gnu_triplet!(gnu_triplet_riscv64, "riscv64-linux-gnu", Some(RISCV64));
+    check_from_gnu_triplet!(gnu_triplet_x32, "x86_64-linux-gnux32", Some(X32));
+    check_from_gnu_triplet!(gnu_triplet_hurd, "i686-gnu", Some(HURD_I386));
+    check_from_gnu_triplet!(gnu_triplet_hurd_vendor, "i686-pc-gnu", Some(HURD_I386));
+    check_from_gnu_triplet!(
+        gnu_triplet_kfreebsd,
+        "x86_64-kfreebsd-gnu",
+        Some(KFREEBSD_AMD64)
+    );
+    check_from_gnu_triplet!(gnu_triplet_mingw, "x86_64-w64-mingw32", None);
+    check_from_gnu_triplet!(gnu_triplet_bare_mingw, "mingw32", None);
+    check_from_gnu_triplet!(gnu_triplet_empty, "", None);
+    check_from_gnu_triplet!(gnu_triplet_musl, "x86_64-linux-musl", None);
+
+    check_same_kernel!(same_kernel_amd64_i386, AMD64, I386, true);
+    check_same_kernel!(same_kernel_amd64_amd64, AMD64, AMD64, true);
+    check_same_kernel!(same_kernel_amd64_kfreebsd, AMD64, KFREEBSD_AMD64, false);
+    check_same_kernel!(same_kernel_hurd_i386, HURD_I386, I386, false);
+    check_same_kernel!(same_kernel_source_all, SOURCE, ALL, false);
+    check_same_kernel!(same_kernel_source_source, SOURCE, SOURCE, false);
+    check_same_kernel!(same_kernel_all_amd64, ALL, AMD64, false);
+    check_same_kernel!(same_kernel_all_all, ALL, ALL, false);
+    check_same_kernel!(same_kernel_any_any, ANY, ANY, false);
+    check_same_kernel!(same_kernel_any_amd64, ANY, AMD64, false);
+    check_same_kernel!(same_kernel_any_source, ANY, SOURCE, false);
+    check_same_kernel!(
+        same_kernel_linux_any_amd64,
+        "linux-any".parse::<Architecture>().unwrap(),
+        AMD64,
+        false
+    );
+    check_same_kernel!(
+        same_kernel_linux_any_linux_any,
+        "linux-any".parse::<Architecture>().unwrap(),
+        "linux-any".parse::<Architecture>().unwrap(),
+        false
+    );
+
+    #[test]
+    fn is_any_linux() {
+        assert!(AMD64.is_any_linux());
+        assert!(ARM64.is_any_linux());
+        assert!(I386.is_any_linux());
+        assert!(!HURD_I386.is_any_linux());
+        assert!(!KFREEBSD_AMD64.is_any_linux());
+        assert!(!SOURCE.is_any_linux());
+        assert!(!ALL.is_any_linux());
+        assert!(!ANY.is_any_linux());
+        assert!(!"linux-any".parse::<Architecture>().unwrap().is_any_linux());
+        assert!(!"any-amd64".parse::<Architecture>().unwrap().is_any_linux());
+    }
+
+    #[test]
+    fn is_gnu_userland() {
+        assert!(AMD64.is_gnu_userland());
+        assert!(HURD_I386.is_gnu_userland());
+        assert!(KFREEBSD_AMD64.is_gnu_userland());
+        assert!(
+            !"musl-linux-amd64"
+                .parse::<Architecture>()
+                .unwrap()
+                .is_gnu_userland()
+        );
+        assert!(!SOURCE.is_gnu_userland());
+        assert!(!ALL.is_gnu_userland());
+    }
+
+    #[test]
+    fn kernel() {
+        assert_eq!("linux", AMD64.kernel());
+        assert_eq!("linux", ARMHF.kernel());
+        assert_eq!("kfreebsd", KFREEBSD_AMD64.kernel());
+        assert_eq!("hurd", HURD_I386.kernel());
+        assert_eq!("", SOURCE.kernel());
+        assert_eq!("", ALL.kernel());
+    }
+
     #[test]
     fn bad_from_parts() {
         assert!(Architecture::from_parts("a", "b", "c", "d").is_ok());
@@ -825,6 +1051,10 @@ mod test {
 
     fails!(fails_empty, "");
     fails!(fails_5, "any-any-any-any-any");
+    fails!(fails_trailing_dash, "linux-");
+    fails!(fails_trailing_dash_any, "any-");
+    fails!(fails_leading_dash, "-amd64");
+    fails!(fails_double_dash, "linux--any");
 
     round_trip!(rt_any, "any", "any");
     round_trip!(rt_any_any, "any-any", "any");
@@ -835,6 +1065,9 @@ mod test {
     round_trip!(rt_any_linux_any, "any-linux-any", "linux-any");
     round_trip!(rt_any_any_linux_any, "any-any-linux-any", "linux-any");
 
+    round_trip!(rt_any_amd64, "any-amd64", "any-amd64");
+    round_trip!(rt_any_any_any_amd64, "any-any-any-amd64", "any-amd64");
+    round_trip!(rt_any_gnu_any_any, "any-gnu-any-any", "gnu-any-any");
     round_trip!(rt_linux_amd64, "linux-amd64", "amd64");
     round_trip!(rt_knetbsd_i386, "knetbsd-i386", "knetbsd-i386");
 
@@ -957,6 +1190,44 @@ mod test {
         assert!(linux_any.is(&ANY));
     }
 
+    #[test]
+    fn test_is_concrete() {
+        assert!(AMD64.is_concrete());
+        assert!(ARMHF.is_concrete());
+        assert!(HURD_I386.is_concrete());
+        assert!(!ANY.is_concrete());
+        assert!(!ALL.is_concrete());
+        assert!(!SOURCE.is_concrete());
+        assert!(!NATIVE.is_concrete());
+
+        let linux_any: Architecture = "linux-any".parse().unwrap();
+        assert!(!linux_any.is_concrete());
+
+        let any_amd64: Architecture = "any-amd64".parse().unwrap();
+        assert!(!any_amd64.is_concrete());
+    }
+
+    #[test]
+    fn test_wildcard_round_trip() {
+        // Every combination of wildcard and concrete parts should format
+        // to something which parses back to the same Architecture, and
+        // formatting that again should give the same string.
+        for abi in ["any", "base", "eabihf"] {
+            for libc in ["any", "gnu", "musl"] {
+                for os in ["any", "linux", "hurd", "kfreebsd"] {
+                    for cpu in ["any", "amd64", "arm", "i386"] {
+                        let long = format!("{abi}-{libc}-{os}-{cpu}");
+                        let arch: Architecture = long.parse().unwrap();
+                        let short = arch.to_string();
+                        let again: Architecture = short.parse().unwrap();
+                        assert_eq!(arch, again, "{long} formatted as {short}");
+                        assert_eq!(short, again.to_string(), "{long}");
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_against_tupletable() {
         use std::{
@@ -20,9 +20,13 @@
 
 //! Rust types to handle Deserialization of a Debian archive files.
 
+mod pin;
+mod preferences;
 mod sources_list;
 mod yes_no_force;
 
+pub use pin::{Pin, PinParseError};
+pub use preferences::Preferences;
 pub use sources_list::SourcesList;
 pub use yes_no_force::YesNoForce;
 
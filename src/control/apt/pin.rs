@@ -0,0 +1,194 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use std::str::FromStr;
+
+/// Error conditions which may be encountered when parsing a [Pin].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PinParseError {
+    /// The `Pin` was empty.
+    Empty,
+
+    /// The `Pin` wasn't a `release`, `version` or `origin` pin.
+    UnknownType(String),
+
+    /// The `Pin` type was given, but nothing to match against.
+    MissingValue,
+}
+crate::errors::error_enum!(PinParseError);
+
+/// What a `Pin` field in an `apt_preferences(5)` file selects the
+/// versions of a package by.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pin {
+    /// Select versions from a release matching all of the provided
+    /// properties of the release's `Release` file, such as `a=stable`
+    /// (archive) or `n=bookworm` (codename), as a list of keys and values.
+    /// A bare value (like `release 12`) is kept with an empty key.
+    Release(Vec<(String, String)>),
+
+    /// Select versions matching a version, which may contain `*` globs,
+    /// such as `5.8*`.
+    Version(String),
+
+    /// Select versions from the given host, such as `deb.debian.org`. An
+    /// empty origin matches a local directory.
+    Origin(String),
+}
+
+crate::control::macros::def_serde_traits_for!(Pin);
+
+impl std::fmt::Display for Pin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Release(properties) => {
+                write!(f, "release ")?;
+                for (idx, (key, value)) in properties.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ", ")?;
+                    }
+                    if key.is_empty() {
+                        write!(f, "{value}")?;
+                    } else {
+                        write!(f, "{key}={value}")?;
+                    }
+                }
+                Ok(())
+            }
+            Self::Version(version) => write!(f, "version {version}"),
+            Self::Origin(origin) => write!(f, "origin \"{origin}\""),
+        }
+    }
+}
+
+impl FromStr for Pin {
+    type Err = PinParseError;
+
+    fn from_str(pin: &str) -> Result<Self, Self::Err> {
+        let pin = pin.trim();
+        if pin.is_empty() {
+            return Err(PinParseError::Empty);
+        }
+
+        let (kind, value) = match pin.split_once(char::is_whitespace) {
+            Some((kind, value)) => (kind, value.trim()),
+            None => (pin, ""),
+        };
+
+        match kind.to_ascii_lowercase().as_str() {
+            "release" => {
+                let properties = value
+                    .split(',')
+                    .map(|property| property.trim())
+                    .filter(|property| !property.is_empty())
+                    .map(|property| match property.split_once('=') {
+                        Some((key, value)) => (key.trim().to_owned(), value.trim().to_owned()),
+                        None => (String::new(), property.to_owned()),
+                    })
+                    .collect::<Vec<_>>();
+                if properties.is_empty() {
+                    return Err(PinParseError::MissingValue);
+                }
+                Ok(Self::Release(properties))
+            }
+            "version" => {
+                if value.is_empty() {
+                    return Err(PinParseError::MissingValue);
+                }
+                Ok(Self::Version(value.to_owned()))
+            }
+            "origin" => {
+                if value.is_empty() {
+                    return Err(PinParseError::MissingValue);
+                }
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|value| value.strip_suffix('"'))
+                    .unwrap_or(value);
+                Ok(Self::Origin(value.to_owned()))
+            }
+            _ => Err(PinParseError::UnknownType(kind.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::macros::{def_failing_parse_test, def_parse_test};
+
+    def_parse_test!(
+        parse_release,
+        Pin,
+        "release a=stable, n=bookworm",
+        Pin::Release(vec![
+            ("a".to_owned(), "stable".to_owned()),
+            ("n".to_owned(), "bookworm".to_owned()),
+        ])
+    );
+    def_parse_test!(
+        parse_release_bare,
+        Pin,
+        "release 12",
+        Pin::Release(vec![("".to_owned(), "12".to_owned())])
+    );
+    def_parse_test!(
+        parse_version,
+        Pin,
+        "version 5.8*",
+        Pin::Version("5.8*".to_owned())
+    );
+    def_parse_test!(
+        parse_origin,
+        Pin,
+        "origin \"deb.debian.org\"",
+        Pin::Origin("deb.debian.org".to_owned())
+    );
+    def_parse_test!(
+        parse_origin_local,
+        Pin,
+        "origin \"\"",
+        Pin::Origin("".to_owned())
+    );
+    def_parse_test!(
+        parse_case,
+        Pin,
+        "Release o=Debian",
+        Pin::Release(vec![("o".to_owned(), "Debian".to_owned())])
+    );
+
+    def_failing_parse_test!(parse_empty, Pin, "");
+    def_failing_parse_test!(parse_unknown, Pin, "label foo");
+    def_failing_parse_test!(parse_no_value, Pin, "version");
+
+    #[test]
+    fn round_trip() {
+        for pin in [
+            "release a=stable, n=bookworm",
+            "release 12",
+            "version 5.8*",
+            "origin \"deb.debian.org\"",
+        ] {
+            assert_eq!(pin, pin.parse::<Pin>().unwrap().to_string());
+        }
+    }
+}
+
+// vim: foldmethod=marker
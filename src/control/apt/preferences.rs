@@ -0,0 +1,133 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+#[cfg(feature = "serde")]
+use ::serde::{Deserialize, Serialize};
+
+use super::Pin;
+
+/// Single stanza of an `apt_preferences(5)` file, such as those found in
+/// `/etc/apt/preferences.d/`, which sets the priority of the versions of
+/// some packages (a "pin").
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "PascalCase"))]
+pub struct Preferences {
+    /// Free-form comment on why this pin exists.
+    pub explanation: Option<String>,
+
+    /// Packages this stanza applies to. This is a space separated list of
+    /// package names, globs (like `perl*`) or regular expressions (like
+    /// `/^gnome-.*/`), or `*` for all packages. Names may be prefixed with
+    /// `src:` to select binaries built from that source.
+    pub package: String,
+
+    /// Which versions of the packages this stanza applies to.
+    pub pin: Pin,
+
+    /// Priority to assign to the selected versions. Versions with a
+    /// higher priority are preferred, 1000 and over will allow downgrades,
+    /// and negative values prevent installation.
+    #[cfg_attr(feature = "serde", serde(rename = "Pin-Priority"))]
+    pub pin_priority: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use crate::control::de;
+        use std::io::{BufReader, Cursor};
+
+        #[test]
+        fn apt_preferences_stanza() {
+            let preferences: Preferences = de::from_str(
+                "\
+Package: perl
+Pin: version 5.34*
+Pin-Priority: 1001
+",
+            )
+            .unwrap();
+            assert_eq!("perl", preferences.package);
+            assert_eq!(Pin::Version("5.34*".to_owned()), preferences.pin);
+            assert_eq!(1001, preferences.pin_priority);
+            assert_eq!(None, preferences.explanation);
+        }
+
+        #[test]
+        fn apt_preferences_file() {
+            let mut reader = BufReader::new(Cursor::new(
+                "\
+Explanation: Prefer packages from backports for the kernel.
+Package: src:linux
+Pin: release a=bookworm-backports
+Pin-Priority: 500
+
+Package: *
+Pin: release o=Debian, n=trixie
+Pin-Priority: -10
+
+Package: *
+Pin: origin \"deb.example.com\"
+Pin-Priority: 900
+",
+            ));
+
+            let preferences = de::from_reader_iter::<Preferences, _>(&mut reader)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(3, preferences.len());
+
+            assert_eq!(
+                Some("Prefer packages from backports for the kernel."),
+                preferences[0].explanation.as_deref()
+            );
+            assert_eq!("src:linux", preferences[0].package);
+            assert_eq!(
+                Pin::Release(vec![("a".to_owned(), "bookworm-backports".to_owned())]),
+                preferences[0].pin
+            );
+            assert_eq!(500, preferences[0].pin_priority);
+
+            assert_eq!("*", preferences[1].package);
+            assert_eq!(
+                Pin::Release(vec![
+                    ("o".to_owned(), "Debian".to_owned()),
+                    ("n".to_owned(), "trixie".to_owned()),
+                ]),
+                preferences[1].pin
+            );
+            assert_eq!(-10, preferences[1].pin_priority);
+
+            assert_eq!(
+                Pin::Origin("deb.example.com".to_owned()),
+                preferences[2].pin
+            );
+            assert_eq!(900, preferences[2].pin_priority);
+        }
+    }
+}
+
+// vim: foldmethod=marker
@@ -18,17 +18,48 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
-use crate::{architecture::Architecture, control::Delimited};
+use crate::{architecture::Architecture, control::Delimited, release::Release};
 
 /// List of [Architecture] values, seperated with a space.
 pub type Architectures = Delimited<' ', Architecture>;
 
+impl Architectures {
+    /// Return `Some(true)` if every concrete [Architecture] listed (such
+    /// as the `Architecture` of an upload's `.changes`) is supported by the
+    /// [Release]. The special `source` and `all` architectures (and any
+    /// wildcards) aren't tied to a port, so they're ignored.
+    ///
+    /// Releases which haven't happened yet (like [crate::release::FORKY])
+    /// don't have any [Release::architectures] listed, since nothing is
+    /// known until they release. This returns `None` for those, the same
+    /// way [crate::release::architecture_delta] treats them as unknown.
+    ///
+    /// ```
+    /// use deb::{control::Architectures, release};
+    ///
+    /// let upload: Architectures = "source all amd64".parse().unwrap();
+    /// assert_eq!(Some(true), upload.is_subset_of_release(&release::BOOKWORM));
+    /// assert_eq!(None, upload.is_subset_of_release(&release::FORKY));
+    /// ```
+    pub fn is_subset_of_release(&self, release: &Release) -> Option<bool> {
+        if release.architectures.is_empty() {
+            return None;
+        }
+        Some(
+            self.iter()
+                .filter(|arch| arch.is_concrete())
+                .all(|arch| release.architectures.contains(arch)),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Architectures;
     use crate::{
         architecture::{self, Architecture},
         control::{Delimited, def_failing_parse_test, def_parse_test},
+        release,
     };
 
     def_parse_test!(
@@ -56,6 +87,27 @@ mod tests {
     );
 
     def_failing_parse_test!(fail_bad_arch, Architectures, "foo-bar-baz-bar-foo");
+
+    #[test]
+    fn is_subset_of_release() {
+        let upload: Architectures = "source amd64 riscv64".parse().unwrap();
+        assert_eq!(Some(false), upload.is_subset_of_release(&release::BOOKWORM));
+        assert_eq!(Some(true), upload.is_subset_of_release(&release::TRIXIE));
+
+        let upload: Architectures = "source all".parse().unwrap();
+        assert_eq!(Some(true), upload.is_subset_of_release(&release::BOOKWORM));
+
+        let upload: Architectures = "".parse().unwrap();
+        assert_eq!(Some(true), upload.is_subset_of_release(&release::BOOKWORM));
+    }
+
+    #[test]
+    fn is_subset_of_release_unknown() {
+        // nothing is known about the ports of a release until it happens.
+        let upload: Architectures = "source amd64".parse().unwrap();
+        assert_eq!(None, upload.is_subset_of_release(&release::FORKY));
+        assert_eq!(None, upload.is_subset_of_release(&release::DUKE));
+    }
 }
 
 // vim: foldmethod=marker
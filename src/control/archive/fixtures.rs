@@ -0,0 +1,38 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+//! `Packages` and `Release` fixtures shared between the tests of this module.
+
+/// First 100 stanzas of the bookworm `main/binary-amd64/Packages` index.
+pub(super) const BOOKWORM_PACKAGES: &str = include_str!(
+    "../../../tests/generated-fixture-tests/fixtures/unsigned/archive/package/bookworm-main-amd64-head.good"
+);
+
+/// The bookworm 12.8 `Release` file, without its signature.
+pub(super) const STABLE_RELEASE: &str = include_str!(
+    "../../../tests/generated-fixture-tests/fixtures/unsigned/archive/release/stable-release.good"
+);
+
+/// The bookworm-updates `Release` file, which carries a `Valid-Until` date.
+pub(super) const BOOKWORM_UPDATES_RELEASE: &str = include_str!(
+    "../../../tests/generated-fixture-tests/fixtures/unsigned/archive/release/bookworm-updates.good"
+);
+
+// vim: foldmethod=marker
@@ -0,0 +1,101 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+use super::Package;
+use crate::control::de;
+use std::{
+    collections::BTreeMap,
+    io::{BufReader, Read},
+};
+
+/// Stream every [Package] from the provided `Packages` index, and group
+/// them by the name of the source package they were built from.
+///
+/// The source package name is taken from the `Source` field (ignoring any
+/// version given along with it), or is the binary package's own name if
+/// there's no `Source` field. Packages are kept in the order they're found
+/// in the index.
+pub fn group_by_source<ReadT>(reader: ReadT) -> Result<BTreeMap<String, Vec<Package>>, de::Error>
+where
+    ReadT: Read,
+{
+    let mut reader = BufReader::new(reader);
+    let mut ret: BTreeMap<String, Vec<Package>> = BTreeMap::new();
+    for package in de::from_reader_iter::<Package, _>(&mut reader) {
+        let package = package?;
+        let source = match &package.control.source {
+            Some(source) => source.name.clone(),
+            None => package.control.package.clone(),
+        };
+        ret.entry(source).or_default().push(package);
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::control::archive::fixtures::BOOKWORM_PACKAGES;
+
+    macro_rules! check_group {
+        ($grouped:expr, $source:expr, $packages:expr) => {
+            assert_eq!(
+                $packages.to_vec(),
+                $grouped[$source]
+                    .iter()
+                    .map(|package| package.control.package.as_str())
+                    .collect::<Vec<_>>()
+            );
+        };
+    }
+
+    #[test]
+    fn group() {
+        let grouped = group_by_source(BOOKWORM_PACKAGES.as_bytes()).unwrap();
+
+        check_group!(
+            grouped,
+            "389-ds-base",
+            [
+                "389-ds",
+                "389-ds-base",
+                "389-ds-base-dev",
+                "389-ds-base-libs",
+                "cockpit-389-ds",
+                "python3-lib389",
+            ]
+        );
+        check_group!(grouped, "0ad-data", ["0ad-data", "0ad-data-common"]);
+        // Source with a version, for a binNMU.
+        check_group!(grouped, "4pane", ["4pane"]);
+        check_group!(grouped, "3270font", ["fonts-3270"]);
+        check_group!(grouped, "0ad", ["0ad"]);
+    }
+
+    #[test]
+    fn group_bad_package() {
+        assert!(group_by_source("Package: hello\n".as_bytes()).is_err());
+    }
+}
+
+// vim: foldmethod=marker
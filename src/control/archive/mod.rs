@@ -21,10 +21,28 @@
 //! Rust types to handle Deserialization of a Debian archive files.
 
 mod package;
+mod pool;
 mod release;
 mod source;
 
+#[cfg(all(test, feature = "serde"))]
+mod fixtures;
+
+#[cfg(feature = "serde")]
+mod group_by_source;
+#[cfg(feature = "serde")]
+mod reverse_depends;
+
 pub use package::Package;
-pub use release::Release;
+pub use pool::{name_prefix, pool_path};
+pub use release::{HashAlgo, Release, ReleaseFileChecksums};
+
+#[cfg(all(feature = "sequoia", feature = "chrono", feature = "serde"))]
+pub use release::ReleaseError;
+
+#[cfg(feature = "serde")]
+pub use group_by_source::group_by_source;
+#[cfg(feature = "serde")]
+pub use reverse_depends::ReverseDepends;
 
 // vim: foldmethod=marker
@@ -18,7 +18,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
-use crate::control::{DigestMd5, DigestSha256, package};
+use crate::control::{DigestMd5, DigestSha256, DigestSha512, package};
 
 #[cfg(feature = "serde")]
 use ::serde::{Deserialize, Serialize};
@@ -44,6 +44,10 @@ pub struct Package {
     #[cfg_attr(feature = "serde", serde(rename = "SHA256"))]
     pub sha256: DigestSha256,
 
+    /// SHA512 hash of the `.deb` file, if the archive provides one.
+    #[cfg_attr(feature = "serde", serde(rename = "SHA512"))]
+    pub sha512: Option<DigestSha512>,
+
     /// Path within the Debian archive to the specific `.deb` file.
     pub filename: String,
 
@@ -56,6 +60,17 @@ pub struct Package {
     pub description_md5: DigestMd5,
 }
 
+impl Package {
+    /// Return the path within the Debian archive to the `.deb` file, as
+    /// found in the `Filename` field (for instance,
+    /// `pool/main/f/fluxbox/fluxbox_1.3.7-1+b1_amd64.deb`).
+    ///
+    /// See [super::pool_path] to compute this path from the source name.
+    pub fn pool_path(&self) -> &str {
+        &self.filename
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "serde")]
@@ -64,7 +79,10 @@ mod tests {
     #[cfg(feature = "serde")]
     mod serde {
         use super::*;
-        use crate::{architecture, control::de};
+        use crate::{
+            architecture,
+            control::{archive, de, ser},
+        };
 
         macro_rules! test_package {
             ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
@@ -100,6 +118,10 @@ MD5sum: e9ae48ab62d609faaafdd034353a28d7
 SHA256: 7eaf5da83ab47fce0937b348640aec52c96ae5193b809d01168c5c81bd7f4645
 ", |package| {
             assert_eq!("fluxbox", package.control.package);
+            assert_eq!(
+                archive::pool_path("main", "fluxbox", "fluxbox_1.3.7-1+b1_amd64.deb"),
+                package.pool_path()
+            );
             assert_eq!(architecture::AMD64, package.control.architecture.unwrap());
             // assert_eq!(4128, package.control.installed_size.unwrap());
             assert!(package.control.depends.is_some());
@@ -109,7 +131,85 @@ SHA256: 7eaf5da83ab47fce0937b348640aec52c96ae5193b809d01168c5c81bd7f4645
             assert_eq!(4128, *package.control.installed_size.unwrap());
             assert_eq!("pool/main/f/fluxbox/fluxbox_1.3.7-1+b1_amd64.deb", package.filename);
             assert_eq!(1226140, package.size);
+            assert!(package.sha512.is_none());
+        });
+
+        test_package!(parse_sha512, "\
+Package: hello
+Version: 2.10-3
+Installed-Size: 280
+Maintainer: Santiago Vila <sanvila@debian.org>
+Architecture: amd64
+Depends: libc6 (>= 2.34)
+Description: example package based on GNU hello
+Description-md5: d4bd8c5fa8a77d2da2fd5e08f4e3f5ab
+Section: devel
+Priority: optional
+Filename: pool/main/h/hello/hello_2.10-3_amd64.deb
+Size: 53324
+MD5sum: d36abefbc87d8dfb7704238f0aee0e90
+SHA256: 052cb5fdfa86bb3485d6194d9ae2fd1cabbccbdd9c7da3258aed1674b288bbf9
+SHA512: cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e
+", |package| {
+            assert_eq!("hello", package.control.package);
+            assert_eq!(
+                "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+                package.sha512.unwrap().to_string()
+            );
         });
+
+        const CARGO_DEPENDS: &str = "libc6 (>= 2.39), libcurl4t64 (>= 7.28.0), libgcc-s1 (>= 4.2), libgit2-1.8 (>= 1.8.1), libsqlite3-0 (>= 3.5.9), libssh2-1t64 (>= 1.2.5), libssl3t64 (>= 3.0.0), zlib1g (>= 1:1.2.3.4), rustc (= 1.82.0+dfsg1-2), binutils, gcc | clang | c-compiler";
+
+        test_package!(
+            archive_main_riscv64_cargo,
+            &format!(
+                "\
+Package: cargo
+Source: rustc
+Version: 1.82.0+dfsg1-2
+Installed-Size: 18664
+Maintainer: Debian Rust Maintainers <pkg-rust-maintainers@alioth-lists.debian.net>
+Architecture: riscv64
+Depends: {CARGO_DEPENDS}
+Suggests: cargo-doc, python3
+Description: Rust package manager
+Multi-Arch: allowed
+Homepage: http://www.rust-lang.org/
+Description-md5: e3c7d8ff9b0415379acb83f0119168b0
+Section: rust
+Priority: optional
+Filename: pool/main/r/rustc/cargo_1.82.0+dfsg1-2_riscv64.deb
+Size: 5687316
+MD5sum: 5d6db6c5c651ebdf63e467c2ffcd4f78
+SHA256: d55cd074cf203b4a07a42c353e1fe5becdb063d2f30a5688cc5cf7f00e12c139
+"
+            ),
+            |package| {
+                assert_eq!(
+                    Some(&architecture::RISCV64),
+                    package.control.architecture.as_ref()
+                );
+
+                let depends = package.control.depends.as_ref().unwrap();
+                assert_eq!(11, depends.relations.len());
+                assert_eq!(3, depends.relations[10].packages.len());
+                assert_eq!(CARGO_DEPENDS, depends.to_string());
+
+                let encoded = ser::to_string(&package).unwrap();
+                assert!(encoded.contains(&format!("\nDepends: {CARGO_DEPENDS}\n")));
+
+                // absent fields are written out empty. Empty relation fields
+                // parse back as an empty Dependency, but others (such as
+                // `Package-Type` or `SHA512`) fail to parse, so drop them.
+                let encoded = encoded
+                    .lines()
+                    .filter(|line| !line.ends_with(':'))
+                    .map(|line| format!("{line}\n"))
+                    .collect::<String>();
+                let decoded: Package = de::from_str(&encoded).unwrap();
+                assert_eq!(package.control.depends, decoded.control.depends);
+            }
+        );
     }
 }
 
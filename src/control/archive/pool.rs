@@ -0,0 +1,117 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+/// Return the directory prefix used to shard the `pool/` by source name.
+///
+/// Sources starting with `lib` are sharded by their first four characters
+/// (`libf` for `libfoo`), and everything else by the first character.
+///
+/// ```
+/// use deb::control::archive::name_prefix;
+///
+/// assert_eq!("r", name_prefix("rustc"));
+/// assert_eq!("libf", name_prefix("libfoo"));
+/// ```
+pub fn name_prefix(source: &str) -> String {
+    let len = if source.starts_with("lib") { 4 } else { 1 };
+    match source.char_indices().nth(len) {
+        Some((idx, _)) => source[..idx].to_owned(),
+        None => source.to_owned(),
+    }
+}
+
+/// Compute the path to a file within the Debian archive, following the
+/// `pool/<area>/<prefix>/<source>/<file>` layout, where `area` is the
+/// archive area (such as `main` or `non-free-firmware`), `source` is the
+/// name of the source package, and `file` is the name of the file within
+/// that source's directory.
+///
+/// ```
+/// use deb::control::archive::pool_path;
+///
+/// assert_eq!(
+///     "pool/main/r/rustc/cargo_1.85.0+dfsg1-1_riscv64.deb",
+///     pool_path("main", "rustc", "cargo_1.85.0+dfsg1-1_riscv64.deb"),
+/// );
+/// ```
+pub fn pool_path(area: &str, source: &str, file: &str) -> String {
+    format!("pool/{}/{}/{}/{}", area, name_prefix(source), source, file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! check_pool_path {
+        ($name:ident, $area:expr, $source:expr, $file:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!($expected, pool_path($area, $source, $file));
+            }
+        };
+    }
+
+    macro_rules! check_name_prefix {
+        ($name:ident, $source:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!($expected, name_prefix($source));
+            }
+        };
+    }
+
+    check_name_prefix!(name_prefix_simple, "rustc", "r");
+    check_name_prefix!(name_prefix_lib, "libfoo", "libf");
+    check_name_prefix!(name_prefix_lib_only, "lib", "lib");
+    check_name_prefix!(name_prefix_libc, "libc", "libc");
+    check_name_prefix!(name_prefix_short, "a", "a");
+    check_name_prefix!(name_prefix_not_lib, "li", "l");
+
+    check_pool_path!(
+        pool_path_simple,
+        "main",
+        "rustc",
+        "cargo_1.85.0+dfsg1-1_riscv64.deb",
+        "pool/main/r/rustc/cargo_1.85.0+dfsg1-1_riscv64.deb"
+    );
+    check_pool_path!(
+        pool_path_lib,
+        "main",
+        "libfoo",
+        "libfoo1_1.0-1_amd64.deb",
+        "pool/main/libf/libfoo/libfoo1_1.0-1_amd64.deb"
+    );
+    check_pool_path!(
+        pool_path_contrib,
+        "contrib",
+        "fluxbox",
+        "fluxbox_1.3.7-1.dsc",
+        "pool/contrib/f/fluxbox/fluxbox_1.3.7-1.dsc"
+    );
+    check_pool_path!(
+        pool_path_lib_only,
+        "main",
+        "lib",
+        "lib_1.0-1.dsc",
+        "pool/main/lib/lib/lib_1.0-1.dsc"
+    );
+}
+
+// vim: foldmethod=marker
@@ -21,9 +21,12 @@
 #[cfg(feature = "serde")]
 use ::serde::{Deserialize, Serialize};
 
-use crate::control::{
-    Architectures, CommaDelimitedStrings, DateTime2822, FileDigestMd5, FileDigestSha1,
-    FileDigestSha256, FileDigestSha512, SpaceDelimitedStrings,
+use crate::{
+    architecture::Architecture,
+    control::{
+        Architectures, CommaDelimitedStrings, DateTime2822, FileDigest, FileDigestMd5,
+        FileDigestSha1, FileDigestSha256, FileDigestSha512, SpaceDelimitedStrings,
+    },
 };
 
 /// Debian archive `Release` file, as seen at filepaths like
@@ -99,6 +102,7 @@ pub struct Release {
     /// Date after which this [Release] file must be considered "expired",
     /// requiring a newer version of the `Release` file. This helps to prevent
     /// downgrade attacks.
+    #[cfg_attr(feature = "serde", serde(rename = "Valid-Until"))]
     pub valid_until: Option<DateTime2822>,
 
     /// Each line consists of space-separated entries describing the file:
@@ -154,6 +158,13 @@ pub struct Release {
     #[cfg_attr(feature = "serde", serde(rename = "Acquire-By-Hash"))]
     pub acquire_by_hash: Option<bool>,
 
+    /// Space separated list of index types (such as `Packages`) for which
+    /// the archive does not provide `Architecture: all` packages in the
+    /// index of every architecture. Clients must fetch the
+    /// `binary-all` indexes of those types themselves.
+    #[cfg_attr(feature = "serde", serde(rename = "No-Support-for-Architecture-all"))]
+    pub no_support_for_architecture_all: Option<SpaceDelimitedStrings>,
+
     /// An optional field containing a comma separated list of OpenPGP key
     /// fingerprints to be used for validating the next Release file. The
     /// fingerprints must consist only of hex digits and may not contain
@@ -190,4 +201,739 @@ pub struct Release {
     pub snapshots: Option<String>,
 }
 
+/// All checksums listed in a [Release] for a single index file, as returned
+/// by [Release::checksums_for].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReleaseFileChecksums<'a> {
+    /// Entry from the `MD5Sum` field, if the file is listed there.
+    ///
+    /// Note: The MD5 checksum is considered weak, and should never be assumed
+    /// to be sufficient for secure verification.
+    pub md5: Option<&'a FileDigestMd5>,
+
+    /// Entry from the `SHA1` field, if the file is listed there.
+    ///
+    /// Note: The SHA-1 checksum is considered weak, and should never be
+    /// assumed to be sufficient for secure verification.
+    pub sha1: Option<&'a FileDigestSha1>,
+
+    /// Entry from the `SHA256` field, if the file is listed there.
+    pub sha256: Option<&'a FileDigestSha256>,
+
+    /// Entry from the `SHA512` field, if the file is listed there.
+    pub sha512: Option<&'a FileDigestSha512>,
+}
+
+/// Hash algorithms used for the checksum fields of a [Release], such as
+/// when looking up an index by its `by-hash` path with
+/// [Release::by_hash_path].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HashAlgo {
+    /// MD5, from the `MD5Sum` field.
+    ///
+    /// Note: The MD5 checksum is considered weak, and should never be assumed
+    /// to be sufficient for secure verification.
+    Md5,
+
+    /// SHA-1, from the `SHA1` field.
+    ///
+    /// Note: The SHA-1 checksum is considered weak, and should never be
+    /// assumed to be sufficient for secure verification.
+    Sha1,
+
+    /// SHA-256, from the `SHA256` field.
+    Sha256,
+
+    /// SHA-512, from the `SHA512` field.
+    Sha512,
+}
+
+impl HashAlgo {
+    /// Name of the directory under `by-hash` holding indexes named by
+    /// this hash, which is the same as the name of the [Release] field.
+    pub fn by_hash_dir(&self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5Sum",
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+            Self::Sha512 => "SHA512",
+        }
+    }
+}
+
+fn find_file_digest<'a, const HASH_LEN: usize>(
+    digests: &'a Option<Vec<FileDigest<HASH_LEN>>>,
+    path: &str,
+) -> Option<&'a FileDigest<HASH_LEN>> {
+    digests.as_ref()?.iter().find(|digest| digest.path == path)
+}
+
+impl Release {
+    /// Return the [Architecture]s served by this archive, as listed in the
+    /// `Architectures` field, including the special arch `all`. If the
+    /// field is absent, this will be empty.
+    pub fn architectures(&self) -> &[Architecture] {
+        self.architectures
+            .as_ref()
+            .map(|architectures| architectures.get_ref())
+            .unwrap_or_default()
+    }
+
+    /// Return the archive components (such as `main` or `contrib`) served
+    /// by this archive, as listed in the `Components` field. If the field
+    /// is absent, this will be empty.
+    pub fn components(&self) -> &[String] {
+        self.components
+            .as_ref()
+            .map(|components| components.get_ref())
+            .unwrap_or_default()
+    }
+
+    /// Return the `Origin` field (such as `Debian`), trimmed of any
+    /// surrounding whitespace, or `None` if the field is absent.
+    pub fn origin(&self) -> Option<&str> {
+        self.origin.as_deref().map(str::trim)
+    }
+
+    /// Return the `Label` field (such as `Debian`), trimmed of any
+    /// surrounding whitespace, or `None` if the field is absent.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref().map(str::trim)
+    }
+
+    /// Return the `Suite` field (such as `stable` or `unstable`), trimmed
+    /// of any surrounding whitespace, or `None` if the field is absent.
+    pub fn suite(&self) -> Option<&str> {
+        self.suite.as_deref().map(str::trim)
+    }
+
+    /// Return the `Codename` field (such as `bookworm` or `sid`), trimmed
+    /// of any surrounding whitespace, or `None` if the field is absent.
+    pub fn codename(&self) -> Option<&str> {
+        self.codename.as_deref().map(str::trim)
+    }
+
+    /// Check to see if this is a development suite, which is to say the
+    /// [Release::suite] is `unstable` or `experimental`. This is `false`
+    /// if the `Suite` field is absent.
+    pub fn is_development(&self) -> bool {
+        matches!(self.suite(), Some("unstable" | "experimental"))
+    }
+
+    /// Check to see if the archive supports fetching indexes from their
+    /// "by-hash" locations, as set by the `Acquire-By-Hash` field. If the
+    /// field is absent, this defaults to `false`.
+    pub fn acquire_by_hash(&self) -> bool {
+        self.acquire_by_hash.unwrap_or(false)
+    }
+
+    /// Return the index types listed in the `No-Support-for-Architecture-all`
+    /// field, or `None` if the field is absent, in which case
+    /// `Architecture: all` packages are listed in the index of every
+    /// architecture.
+    pub fn no_support_for_arch_all(&self) -> Option<&[String]> {
+        self.no_support_for_architecture_all
+            .as_ref()
+            .map(|index_types| index_types.get_ref())
+    }
+
+    /// Return the checksums of the index file at `path` (relative to the
+    /// directory containing the `Release` file, such as
+    /// `main/binary-amd64/Packages.xz`), from each of the checksum fields
+    /// in this [Release].
+    ///
+    /// If the file is not listed in any of the checksum fields, this will
+    /// return `None`.
+    pub fn checksums_for(&self, path: &str) -> Option<ReleaseFileChecksums<'_>> {
+        let checksums = ReleaseFileChecksums {
+            md5: find_file_digest(&self.md5sums, path),
+            sha1: find_file_digest(&self.sha1, path),
+            sha256: find_file_digest(&self.sha256, path),
+            sha512: find_file_digest(&self.sha512, path),
+        };
+
+        if checksums.md5.is_none()
+            && checksums.sha1.is_none()
+            && checksums.sha256.is_none()
+            && checksums.sha512.is_none()
+        {
+            return None;
+        }
+
+        Some(checksums)
+    }
+
+    /// Return the "by-hash" path of the index file at `index_path`
+    /// (relative to the directory containing the `Release` file), using the
+    /// `algo` checksum listed for it in this [Release]. This is the same
+    /// directory as the index, but under `by-hash/<algo>/<digest>`.
+    ///
+    /// If the file isn't listed with an `algo` checksum, this will return
+    /// `None`. The archive only serves these paths if
+    /// [Release::acquire_by_hash] is set.
+    ///
+    /// ```
+    /// use deb::control::archive::{HashAlgo, Release};
+    /// # #[cfg(feature = "serde")]
+    /// # {
+    /// use deb::control::de;
+    ///
+    /// let release: Release = de::from_str("\
+    /// Acquire-By-Hash: yes
+    /// SHA256:
+    ///  c22d03bdd4c7619e1e39e73b4a7b9dfdf1cc1141ed9b10913fbcac58b3a943d0 98581 main/Contents-all.gz
+    /// ").unwrap();
+    ///
+    /// assert_eq!(
+    ///     Some("main/by-hash/SHA256/c22d03bdd4c7619e1e39e73b4a7b9dfdf1cc1141ed9b10913fbcac58b3a943d0"),
+    ///     release.by_hash_path("main/Contents-all.gz", HashAlgo::Sha256).as_deref(),
+    /// );
+    /// # }
+    /// ```
+    pub fn by_hash_path(&self, index_path: &str, algo: HashAlgo) -> Option<String> {
+        let digest = match algo {
+            HashAlgo::Md5 => find_file_digest(&self.md5sums, index_path)?
+                .digest
+                .to_string(),
+            HashAlgo::Sha1 => find_file_digest(&self.sha1, index_path)?.digest.to_string(),
+            HashAlgo::Sha256 => find_file_digest(&self.sha256, index_path)?
+                .digest
+                .to_string(),
+            HashAlgo::Sha512 => find_file_digest(&self.sha512, index_path)?
+                .digest
+                .to_string(),
+        };
+
+        let dir = match index_path.rsplit_once('/') {
+            Some((dir, _)) => format!("{dir}/"),
+            None => String::new(),
+        };
+
+        Some(format!("{dir}by-hash/{}/{digest}", algo.by_hash_dir()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    #![cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+
+    use super::Release;
+    use ::chrono::{NaiveDate, TimeDelta, Utc};
+
+    impl Release {
+        /// Date (in UTC) after which this [Release] is no longer valid,
+        /// parsed from the `Valid-Until` field.
+        fn valid_until_date(&self) -> Option<NaiveDate> {
+            Some(
+                self.valid_until
+                    .as_ref()?
+                    .to_datetime()
+                    .with_timezone(&Utc)
+                    .date_naive(),
+            )
+        }
+
+        /// Check to see if this [Release] has expired as of the date `now`,
+        /// by checking it against the `Valid-Until` field. A [Release] is
+        /// still considered valid on the day it expires.
+        ///
+        /// If there's no `Valid-Until` field, this will return `None`.
+        /// Clients must not rely on a stale [Release] file, since that would
+        /// allow an attacker to replay old (and possibly vulnerable) indexes.
+        pub fn is_expired(&self, now: &NaiveDate) -> Option<bool> {
+            Some(now > &self.valid_until_date()?)
+        }
+
+        /// Return the amount of time between the date `now` and the
+        /// `Valid-Until` field. If this [Release] has already expired, the
+        /// returned [TimeDelta] will be negative.
+        ///
+        /// If there's no `Valid-Until` field, this will return `None`.
+        pub fn time_until_expiry(&self, now: &NaiveDate) -> Option<TimeDelta> {
+            Some(self.valid_until_date()? - *now)
+        }
+    }
+}
+
+/// Error conditions which may be encountered when checking a [Release]
+/// with [Release::verify_and_check_fresh].
+#[cfg(all(feature = "sequoia", feature = "chrono", feature = "serde"))]
+#[derive(Debug)]
+pub enum ReleaseError {
+    /// The detached signature over the [Release] wasn't valid, or wasn't
+    /// made by a key in the keyring. Nothing in the [Release] should be
+    /// trusted.
+    BadSignature(crate::control::OpenPgpValidatorError),
+
+    /// The [Release] was validly signed, but couldn't be parsed.
+    Malformed(crate::control::de::Error),
+
+    /// The [Release] was validly signed, but its `Valid-Until` date has
+    /// passed, so it may be an old [Release] being replayed.
+    Stale,
+}
+#[cfg(all(feature = "sequoia", feature = "chrono", feature = "serde"))]
+crate::errors::error_enum!(ReleaseError);
+
+#[cfg(all(feature = "sequoia", feature = "chrono", feature = "serde"))]
+mod sequoia {
+    #![cfg_attr(
+        docsrs,
+        doc(cfg(all(feature = "sequoia", feature = "chrono", feature = "serde")))
+    )]
+
+    use super::{Release, ReleaseError};
+    use crate::control::{OpenPgpValidator, de};
+    use ::chrono::NaiveDate;
+    use sequoia_openpgp::Fingerprint;
+    use std::io::BufReader;
+
+    impl Release {
+        /// Check the detached OpenPGP signature (`Release.gpg`) over the
+        /// bytes of a `Release` file against the `keyring`, and then check
+        /// that the [Release] hasn't expired as of `now`. This is what a
+        /// client must do before trusting any of the index files the
+        /// [Release] lists.
+        ///
+        /// On success, the fingerprint of each certificate which made a
+        /// valid signature is returned. A bad signature is reported as
+        /// [ReleaseError::BadSignature], and an expired [Release] as
+        /// [ReleaseError::Stale]. A [Release] without a `Valid-Until` field
+        /// never expires, as with [Release::is_expired].
+        pub fn verify_and_check_fresh(
+            keyring: &OpenPgpValidator,
+            release_bytes: &[u8],
+            sig_bytes: &[u8],
+            now: &NaiveDate,
+        ) -> Result<Vec<Fingerprint>, ReleaseError> {
+            let signatures = keyring
+                .validate_detached(release_bytes, sig_bytes)
+                .map_err(ReleaseError::BadSignature)?;
+
+            let release: Release = de::from_reader(&mut BufReader::new(release_bytes))
+                .map_err(ReleaseError::Malformed)?;
+            if release.is_expired(now) == Some(true) {
+                return Err(ReleaseError::Stale);
+            }
+
+            let mut fingerprints: Vec<Fingerprint> = vec![];
+            for (cert, _) in signatures {
+                let fingerprint = cert.fingerprint();
+                if !fingerprints.contains(&fingerprint) {
+                    fingerprints.push(fingerprint);
+                }
+            }
+            Ok(fingerprints)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use sequoia_openpgp::{
+            Cert,
+            cert::CertBuilder,
+            policy::StandardPolicy,
+            serialize::stream::{Message, Signer},
+        };
+        use std::io::Write;
+
+        const RELEASE: &str = "\
+Origin: Debian
+Label: Debian
+Suite: unstable
+Codename: sid
+Date: Wed, 04 Dec 2024 20:12:04 UTC
+Valid-Until: Wed, 11 Dec 2024 20:12:04 UTC
+Architectures: amd64 arm64
+Components: main
+";
+
+        fn generate_cert() -> Cert {
+            let (cert, _) = CertBuilder::new()
+                .add_userid("Archive Signing Key <archive@example.com>")
+                .add_signing_subkey()
+                .generate()
+                .unwrap();
+            cert
+        }
+
+        fn sign_detached(cert: &Cert, data: &[u8]) -> Vec<u8> {
+            let keypair = cert
+                .keys()
+                .with_policy(&StandardPolicy::new(), None)
+                .secret()
+                .for_signing()
+                .next()
+                .unwrap()
+                .key()
+                .clone()
+                .into_keypair()
+                .unwrap();
+
+            let mut sig = vec![];
+            let message = Message::new(&mut sig);
+            let mut signer = Signer::new(message, keypair)
+                .unwrap()
+                .detached()
+                .build()
+                .unwrap();
+            signer.write_all(data).unwrap();
+            signer.finalize().unwrap();
+            sig
+        }
+
+        fn keyring(cert: &Cert) -> OpenPgpValidator {
+            OpenPgpValidator::build()
+                .with_cert(cert.clone())
+                .build()
+                .unwrap()
+        }
+
+        #[test]
+        fn good_fresh() {
+            let cert = generate_cert();
+            let sig = sign_detached(&cert, RELEASE.as_bytes());
+            let now = NaiveDate::from_ymd_opt(2024, 12, 5).unwrap();
+
+            assert_eq!(
+                vec![cert.fingerprint()],
+                Release::verify_and_check_fresh(&keyring(&cert), RELEASE.as_bytes(), &sig, &now)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn good_stale() {
+            let cert = generate_cert();
+            let sig = sign_detached(&cert, RELEASE.as_bytes());
+            let now = NaiveDate::from_ymd_opt(2024, 12, 12).unwrap();
+
+            assert!(matches!(
+                Release::verify_and_check_fresh(&keyring(&cert), RELEASE.as_bytes(), &sig, &now),
+                Err(ReleaseError::Stale)
+            ));
+        }
+
+        #[test]
+        fn bad_signature() {
+            let cert = generate_cert();
+            let now = NaiveDate::from_ymd_opt(2024, 12, 5).unwrap();
+
+            // signature over something else entirely.
+            let sig = sign_detached(&cert, b"Origin: Evil\n");
+            assert!(matches!(
+                Release::verify_and_check_fresh(&keyring(&cert), RELEASE.as_bytes(), &sig, &now),
+                Err(ReleaseError::BadSignature(_))
+            ));
+
+            // good signature, but by a key not in the keyring.
+            let sig = sign_detached(&generate_cert(), RELEASE.as_bytes());
+            assert!(matches!(
+                Release::verify_and_check_fresh(&keyring(&cert), RELEASE.as_bytes(), &sig, &now),
+                Err(ReleaseError::BadSignature(_))
+            ));
+        }
+
+        #[test]
+        fn bad_signature_stale() {
+            // the signature is checked before freshness, so a stale and
+            // unsigned Release is reported as badly signed.
+            let cert = generate_cert();
+            let now = NaiveDate::from_ymd_opt(2024, 12, 12).unwrap();
+            assert!(matches!(
+                Release::verify_and_check_fresh(
+                    &keyring(&cert),
+                    RELEASE.as_bytes(),
+                    b"not a signature",
+                    &now
+                ),
+                Err(ReleaseError::BadSignature(_))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use crate::{
+            architecture,
+            control::{
+                archive::fixtures::{BOOKWORM_UPDATES_RELEASE, STABLE_RELEASE},
+                de,
+            },
+        };
+
+        macro_rules! test_release {
+            ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
+                #[test]
+                fn $name() {
+                    let $parsed = de::from_str::<Release>($data).unwrap();
+                    $block
+                }
+            };
+        }
+
+        test_release!(
+            parse_architectures,
+            "Architectures: all amd64 arm64 armel armhf i386 mips64el ppc64el riscv64 s390x\n",
+            |release| {
+                assert_eq!(10, release.architectures().len());
+                assert!(release.architectures().contains(&architecture::ALL));
+                assert!(release.architectures().contains(&architecture::RISCV64));
+            }
+        );
+
+        test_release!(parse_architectures_stable, STABLE_RELEASE, |release| {
+            assert!(release.architectures().contains(&architecture::AMD64));
+            assert!(!release.architectures().contains(&architecture::RISCV64));
+        });
+
+        test_release!(parse_components, STABLE_RELEASE, |release| {
+            assert_eq!(
+                vec!["main", "contrib", "non-free-firmware", "non-free"],
+                release.components()
+            );
+            assert!(
+                release
+                    .components()
+                    .iter()
+                    .any(|c| c == "non-free-firmware")
+            );
+        });
+
+        #[test]
+        fn parse_missing_architectures_components() {
+            let release = de::from_str::<Release>("Origin: Debian\n").unwrap();
+            assert!(release.architectures().is_empty());
+            assert!(release.components().is_empty());
+        }
+
+        test_release!(parse_header_stable, STABLE_RELEASE, |release| {
+            assert_eq!(Some("Debian"), release.origin());
+            assert_eq!(Some("Debian"), release.label());
+            assert_eq!(Some("stable"), release.suite());
+            assert_eq!(Some("bookworm"), release.codename());
+            assert!(!release.is_development());
+        });
+
+        test_release!(parse_header_updates, BOOKWORM_UPDATES_RELEASE, |release| {
+            assert_eq!(Some("Debian"), release.origin());
+            assert_eq!(Some("Debian"), release.label());
+            assert_eq!(Some("oldstable-updates"), release.suite());
+            assert_eq!(Some("bookworm-updates"), release.codename());
+            assert!(!release.is_development());
+        });
+
+        test_release!(
+            parse_header_unstable,
+            "Origin: Debian\nLabel: Debian\nSuite: unstable\nCodename: sid\n",
+            |release| {
+                assert_eq!(Some("Debian"), release.origin());
+                assert_eq!(Some("unstable"), release.suite());
+                assert_eq!(Some("sid"), release.codename());
+                assert!(release.is_development());
+            }
+        );
+
+        test_release!(
+            parse_header_experimental,
+            "Suite: experimental \nCodename: rc-buggy\n",
+            |release| {
+                assert_eq!(None, release.origin());
+                assert_eq!(None, release.label());
+                assert_eq!(Some("experimental"), release.suite());
+                assert_eq!(Some("rc-buggy"), release.codename());
+                assert!(release.is_development());
+            }
+        );
+
+        #[test]
+        fn parse_header_missing() {
+            let release = de::from_str::<Release>("Origin: Debian\n").unwrap();
+            assert_eq!(None, release.suite());
+            assert!(!release.is_development());
+        }
+
+        test_release!(parse_flags, STABLE_RELEASE, |release| {
+            assert!(release.acquire_by_hash());
+            assert_eq!(
+                Some(&["Packages".to_owned()][..]),
+                release.no_support_for_arch_all()
+            );
+        });
+
+        #[test]
+        fn parse_missing_flags() {
+            let release = de::from_str::<Release>("Origin: Debian\n").unwrap();
+            assert!(!release.acquire_by_hash());
+            assert!(release.no_support_for_arch_all().is_none());
+
+            let release = de::from_str::<Release>("Acquire-By-Hash: no\n").unwrap();
+            assert!(!release.acquire_by_hash());
+        }
+
+        test_release!(by_hash_path, STABLE_RELEASE, |release| {
+            assert_eq!(
+                Some(
+                    "contrib/by-hash/SHA256/c22d03bdd4c7619e1e39e73b4a7b9dfdf1cc1141ed9b10913fbcac58b3a943d0"
+                        .to_owned()
+                ),
+                release.by_hash_path("contrib/Contents-all.gz", HashAlgo::Sha256)
+            );
+            assert_eq!(
+                Some("contrib/by-hash/MD5Sum/d0a0325a97c42fd5f66a8c3e29bcea64".to_owned()),
+                release.by_hash_path("contrib/Contents-all.gz", HashAlgo::Md5)
+            );
+            assert_eq!(
+                Some(
+                    "non-free/source/by-hash/SHA256/371bd27bc8929748d85282c77f6e31926cacee1f3f61a5cac90116104b59638f"
+                        .to_owned()
+                ),
+                release.by_hash_path("non-free/source/Sources.xz", HashAlgo::Sha256)
+            );
+
+            // no SHA1 or SHA512 field at all
+            assert_eq!(
+                None,
+                release.by_hash_path("contrib/Contents-all.gz", HashAlgo::Sha1)
+            );
+            assert_eq!(
+                None,
+                release.by_hash_path("contrib/Contents-all.gz", HashAlgo::Sha512)
+            );
+            // not listed at all
+            assert_eq!(
+                None,
+                release.by_hash_path("main/binary-riscv64/Packages", HashAlgo::Sha256)
+            );
+        });
+
+        #[test]
+        fn by_hash_path_top_level() {
+            let release = de::from_str::<Release>(
+                "\
+SHA1:
+ da39a3ee5e6b4b0d3255bfef95601890afd80709 0 Contents-all
+",
+            )
+            .unwrap();
+            assert_eq!(
+                Some("by-hash/SHA1/da39a3ee5e6b4b0d3255bfef95601890afd80709".to_owned()),
+                release.by_hash_path("Contents-all", HashAlgo::Sha1)
+            );
+        }
+
+        test_release!(parse_no_valid_until, STABLE_RELEASE, |release| {
+            assert!(release.valid_until.is_none());
+        });
+
+        test_release!(parse_valid_until, BOOKWORM_UPDATES_RELEASE, |release| {
+            assert!(release.valid_until.is_some());
+        });
+
+        #[cfg(feature = "chrono")]
+        mod chrono {
+            use super::*;
+            use ::chrono::{NaiveDate, TimeDelta};
+
+            macro_rules! date {
+                ($y:expr, $m:expr, $d:expr) => {
+                    NaiveDate::from_ymd_opt($y, $m, $d).unwrap()
+                };
+            }
+
+            test_release!(expiry_none, STABLE_RELEASE, |release| {
+                assert!(release.is_expired(&date!(2024, 12, 1)).is_none());
+                assert!(release.time_until_expiry(&date!(2024, 12, 1)).is_none());
+            });
+
+            test_release!(expiry_valid, BOOKWORM_UPDATES_RELEASE, |release| {
+                assert_eq!(Some(false), release.is_expired(&date!(2026, 5, 21)));
+                assert_eq!(Some(false), release.is_expired(&date!(2026, 5, 27)));
+                assert_eq!(
+                    Some(TimeDelta::days(6)),
+                    release.time_until_expiry(&date!(2026, 5, 21))
+                );
+            });
+
+            test_release!(expiry_expired, BOOKWORM_UPDATES_RELEASE, |release| {
+                assert_eq!(Some(true), release.is_expired(&date!(2026, 5, 28)));
+                assert_eq!(
+                    Some(TimeDelta::days(-20)),
+                    release.time_until_expiry(&date!(2026, 6, 16))
+                );
+            });
+        }
+
+        test_release!(parse_checksums, STABLE_RELEASE, |release| {
+            assert_eq!(772, release.md5sums.as_ref().unwrap().len());
+            assert_eq!(772, release.sha256.as_ref().unwrap().len());
+            assert!(release.sha1.is_none());
+            assert!(release.sha512.is_none());
+        });
+
+        test_release!(checksums_for_both, STABLE_RELEASE, |release| {
+            let checksums = release.checksums_for("contrib/Contents-all.gz").unwrap();
+            let md5 = checksums.md5.unwrap();
+            assert_eq!("d0a0325a97c42fd5f66a8c3e29bcea64", md5.digest.to_string());
+            assert_eq!(98581, md5.size);
+            let sha256 = checksums.sha256.unwrap();
+            assert_eq!(
+                "c22d03bdd4c7619e1e39e73b4a7b9dfdf1cc1141ed9b10913fbcac58b3a943d0",
+                sha256.digest.to_string()
+            );
+            assert_eq!(98581, sha256.size);
+            assert!(checksums.sha1.is_none());
+            assert!(checksums.sha512.is_none());
+        });
+
+        test_release!(checksums_for_empty, STABLE_RELEASE, |release| {
+            let checksums = release.checksums_for("contrib/Contents-udeb-all").unwrap();
+            assert_eq!(
+                "d41d8cd98f00b204e9800998ecf8427e",
+                checksums.md5.unwrap().digest.to_string()
+            );
+            let sha256 = checksums.sha256.unwrap();
+            assert_eq!(
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                sha256.digest.to_string()
+            );
+            assert_eq!(0, sha256.size);
+        });
+
+        #[test]
+        fn checksums_for_partial() {
+            // every file in the stable fixture is listed under both hashes
+            let release = de::from_str::<Release>(
+                "\
+MD5Sum:
+ d0a0325a97c42fd5f66a8c3e29bcea64    98581 contrib/Contents-all.gz
+SHA256:
+ c22d03bdd4c7619e1e39e73b4a7b9dfdf1cc1141ed9b10913fbcac58b3a943d0    98581 contrib/Contents-all.gz
+ 371bd27bc8929748d85282c77f6e31926cacee1f3f61a5cac90116104b59638f    77940 non-free/source/Sources.xz
+",
+            )
+            .unwrap();
+            let checksums = release.checksums_for("non-free/source/Sources.xz").unwrap();
+            assert!(checksums.md5.is_none());
+            assert_eq!(77940, checksums.sha256.unwrap().size);
+        }
+
+        test_release!(checksums_for_missing, STABLE_RELEASE, |release| {
+            assert!(
+                release
+                    .checksums_for("main/binary-riscv64/Packages")
+                    .is_none()
+            );
+        });
+    }
+}
+
 // vim: foldmethod=marker
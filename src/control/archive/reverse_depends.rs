@@ -0,0 +1,131 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+use super::Package;
+use crate::{control::de, dependency::Dependency};
+use std::{
+    collections::BTreeMap,
+    io::{BufReader, Read},
+};
+
+/// Index from a package name to the names of the packages which depend on
+/// it (through either `Depends` or `Recommends`), built from an archive
+/// `Packages` file.
+///
+/// Every alternative in a relation counts, so a package which
+/// `Depends: foo | bar` is listed as a reverse dependency of both `foo`
+/// and `bar`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReverseDepends {
+    rdepends: BTreeMap<String, Vec<String>>,
+}
+
+impl ReverseDepends {
+    /// Stream every [Package] from the provided `Packages` index, and
+    /// build a [ReverseDepends] from their `Depends` and `Recommends`.
+    pub fn from_reader<ReadT>(reader: ReadT) -> Result<ReverseDepends, de::Error>
+    where
+        ReadT: Read,
+    {
+        let mut reader = BufReader::new(reader);
+        let mut ret = ReverseDepends::default();
+        for package in de::from_reader_iter::<Package, _>(&mut reader) {
+            ret.add(&package?);
+        }
+        Ok(ret)
+    }
+
+    /// Add the reverse edges for the `Depends` and `Recommends` of
+    /// `package` to this index.
+    pub fn add(&mut self, package: &Package) {
+        let control = &package.control;
+        for dependency in [&control.depends, &control.recommends]
+            .into_iter()
+            .flatten()
+        {
+            self.add_dependency(&control.package, dependency);
+        }
+    }
+
+    fn add_dependency(&mut self, name: &str, dependency: &Dependency) {
+        for package in dependency
+            .relations
+            .iter()
+            .flat_map(|relation| relation.packages.iter())
+        {
+            let rdepends = self.rdepends.entry(package.name.clone()).or_default();
+            if let Err(idx) = rdepends.binary_search_by(|rdepend| rdepend.as_str().cmp(name)) {
+                rdepends.insert(idx, name.to_owned());
+            }
+        }
+    }
+
+    /// Return the names of the packages which depend on `name`, sorted
+    /// and without duplicates. Packages nothing depends on return an empty
+    /// slice.
+    pub fn rdepends(&self, name: &str) -> &[String] {
+        self.rdepends.get(name).map(|v| &v[..]).unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::control::archive::fixtures::BOOKWORM_PACKAGES;
+
+    #[test]
+    fn rdepends() {
+        let rdepends = ReverseDepends::from_reader(BOOKWORM_PACKAGES.as_bytes()).unwrap();
+
+        assert_eq!(&["0ad"], rdepends.rdepends("0ad-data"));
+        assert_eq!(
+            &["389-ds-base", "389-ds-base-dev"],
+            rdepends.rdepends("389-ds-base-libs")
+        );
+        assert_eq!(
+            &["389-ds-base", "cockpit-389-ds"],
+            rdepends.rdepends("python3-lib389")
+        );
+        assert_eq!(
+            &["0ad", "3dchess", "9menu", "9wm", "libaa1", "libabiword-3.0"],
+            rdepends.rdepends("libx11-6")
+        );
+        // alternatives count too.
+        assert_eq!(&["0ad-data-common"], rdepends.rdepends("ttf-dejavu-core"));
+    }
+
+    #[test]
+    fn rdepends_none() {
+        let rdepends = ReverseDepends::from_reader(BOOKWORM_PACKAGES.as_bytes()).unwrap();
+        // only ever Suggested.
+        assert!(rdepends.rdepends("0ad").is_empty());
+        assert!(rdepends.rdepends("not-a-package").is_empty());
+    }
+
+    #[test]
+    fn rdepends_bad_package() {
+        assert!(ReverseDepends::from_reader("Package: hello\n".as_bytes()).is_err());
+    }
+}
+
+// vim: foldmethod=marker
@@ -0,0 +1,188 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, SeqAccess, Visitor},
+};
+use std::{marker::PhantomData, ops::Deref, str::FromStr};
+
+/// Name of the newtype struct [AsSequence] serializes as. The
+/// [crate::control::ser] serializer looks for this name to write the
+/// sequence back out as a single space separated line.
+pub(crate) const AS_SEQUENCE: &str = "$deb::control::AsSequence";
+
+/// Wrapper around a space delimited type (such as a
+/// [crate::control::SpaceDelimitedStrings] or a
+/// [crate::control::SpaceDelimitedSeq]) which changes how it's serialized
+/// by serde.
+///
+/// On its own, a space delimited type is always serialized as a single
+/// joined String, since that's how it's written in a control file. That's
+/// less useful in formats like JSON, where a real array is expected.
+/// Wrapping the value in an [AsSequence] will serialize it as a sequence of
+/// Strings to every serde target *except* [crate::control::ser], which
+/// will still write the space-joined String, so the same struct can be
+/// written out as either a control file or JSON.
+///
+/// When deserializing, either a String (which is parsed as `T`) or a
+/// sequence of Strings (which are joined with a space, and then parsed as
+/// `T`) are accepted.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct AsSequence<T>(pub T);
+
+impl<T> Deref for AsSequence<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+struct Elements<'a, InnerT>(&'a [InnerT]);
+
+impl<InnerT> Serialize for Elements<'_, InnerT>
+where
+    InnerT: std::fmt::Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.0.iter().map(|v| v.to_string()))
+    }
+}
+
+impl<T, InnerT> Serialize for AsSequence<T>
+where
+    T: Deref<Target = [InnerT]>,
+    InnerT: std::fmt::Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(AS_SEQUENCE, &Elements(&self.0))
+    }
+}
+
+struct AsSequenceVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for AsSequenceVisitor<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Debug,
+{
+    type Value = AsSequence<T>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a space delimited string or a sequence of strings")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse()
+            .map(AsSequence)
+            .map_err(|e| E::custom(format!("{e:?}")))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values: Vec<String> = vec![];
+        while let Some(value) = seq.next_element::<String>()? {
+            values.push(value);
+        }
+        self.visit_str(&values.join(" "))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for AsSequence<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Debug,
+{
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_any(AsSequenceVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        architecture::{self, Architecture},
+        control::{SpaceDelimitedSeq, SpaceDelimitedStrings, de, ser},
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Test {
+        package: String,
+        binary: AsSequence<SpaceDelimitedStrings>,
+    }
+
+    fn hello() -> Test {
+        Test {
+            package: "hello".to_owned(),
+            binary: AsSequence(SpaceDelimitedStrings::from_str("hello hello-dbg").unwrap()),
+        }
+    }
+
+    #[test]
+    fn as_sequence_control() {
+        let control = ser::to_string(&hello()).unwrap();
+        assert_eq!("Package: hello\nBinary: hello hello-dbg\n", control);
+        assert_eq!(hello(), de::from_str::<Test>(&control).unwrap());
+    }
+
+    #[test]
+    fn as_sequence_json() {
+        let json = serde_json::to_string(&hello()).unwrap();
+        assert_eq!(
+            r#"{"Package":"hello","Binary":["hello","hello-dbg"]}"#,
+            json
+        );
+        assert_eq!(hello(), serde_json::from_str::<Test>(&json).unwrap());
+    }
+
+    #[test]
+    fn as_sequence_empty() {
+        let test = Test {
+            package: "hello".to_owned(),
+            binary: AsSequence(SpaceDelimitedStrings::from_str("").unwrap()),
+        };
+        assert_eq!(
+            r#"{"Package":"hello","Binary":[]}"#,
+            serde_json::to_string(&test).unwrap()
+        );
+        assert_eq!("Package: hello\nBinary:\n", ser::to_string(&test).unwrap());
+    }
+
+    #[test]
+    fn as_sequence_typed() {
+        let archs: AsSequence<SpaceDelimitedSeq<Architecture>> =
+            serde_json::from_str(r#"["amd64", "arm64"]"#).unwrap();
+        assert_eq!(&[architecture::AMD64, architecture::ARM64], &archs[..]);
+        assert_eq!(
+            r#"["amd64","arm64"]"#,
+            serde_json::to_string(&archs).unwrap()
+        );
+    }
+}
+
+// vim: foldmethod=marker
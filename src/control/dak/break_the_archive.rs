@@ -22,7 +22,7 @@
 use ::serde::{Deserialize, Serialize};
 
 /// Debian Maintainer ACL action(s).
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "PascalCase"))]
 pub struct BreakTheArchive {}
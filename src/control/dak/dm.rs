@@ -24,7 +24,7 @@ use ::serde::{Deserialize, Serialize};
 use crate::control::SpaceDelimitedStrings;
 
 /// Debian Maintainer ACL action(s).
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "PascalCase"))]
 pub struct Dm {
@@ -34,10 +34,12 @@ pub struct Dm {
 
     /// List of packages to grant the Debian Maintainer's OpenPGP key
     /// the ability to upload.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub allow: Option<SpaceDelimitedStrings>,
 
     /// List of packages to deny the Debian Maintainer's OpenPGP key
     /// the ability to upload.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub deny: Option<SpaceDelimitedStrings>,
 }
 
@@ -33,6 +33,9 @@
 //! | Manage DM Permissions  | `dm`                  | [Dm]                   |
 //! | Break the Archive      | `break-the-archive`   | [BreakTheArchive]      |
 //! | Process an Upload      | `process-upload`      | [ProcessUpload]        |
+//!
+//! Any other action is kept as a [CommandAction::Unknown], along with the
+//! rest of its fields, rather than failing to decode the whole [Command].
 
 mod break_the_archive;
 mod dm;
@@ -46,12 +49,15 @@ pub use process_upload::ProcessUpload;
 use ::serde::{Deserialize, Serialize};
 
 #[cfg(feature = "serde")]
-use crate::control::de;
+use crate::control::{de, ser};
+
+#[cfg(feature = "serde")]
+use _serde::KnownCommandAction;
 
 /// Command from a Debian Developer uploaded to
 /// [dak](https://ftp-master.debian.org/#dak) to request a change to the
 /// archive, such as granting Debian Maintainer rights via [CommandAction::Dm].
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Command {
     /// Dak command header, this specifies what archive this was uploaded to
     /// in order to prevent reuse between archives.
@@ -69,6 +75,10 @@ pub enum CommandError {
     /// Underlying [de::Error] serde decoding error.
     #[cfg(feature = "serde")]
     De(de::Error),
+
+    /// Underlying [ser::Error] serde encoding error.
+    #[cfg(feature = "serde")]
+    Ser(ser::Error),
 }
 
 #[cfg(feature = "serde")]
@@ -78,30 +88,51 @@ impl From<de::Error> for CommandError {
     }
 }
 
+#[cfg(feature = "serde")]
+impl From<ser::Error> for CommandError {
+    fn from(see: ser::Error) -> Self {
+        Self::Ser(see)
+    }
+}
+
 crate::errors::error_enum!(CommandError);
 
 /// Possible [dak](https://ftp-master.debian.org/#dak) commands which are
 /// uploaded by Debian Developers in order to control the archive.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
-#[cfg_attr(feature = "serde", serde(tag = "Action"))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        from = "de::Fallback<KnownCommandAction>",
+        into = "de::Fallback<KnownCommandAction>"
+    )
+)]
 pub enum CommandAction {
     /// Add or remove Debian Maintainer (DM) rights.
     Dm(Dm),
 
     /// Break the archive
-    #[cfg_attr(feature = "serde", serde(rename = "break-the-archive"))]
     BreakTheArchive(BreakTheArchive),
 
     /// Migrate or block a package.
-    #[cfg_attr(feature = "serde", serde(rename = "process-upload"))]
     ProcessUpload(ProcessUpload),
+
+    /// Action this crate doesn't know about (yet), such as one added to
+    /// dak after this crate was released.
+    Unknown {
+        /// Value of the `Action` field.
+        action: String,
+
+        /// Every other field of the paragraph, and its value, in the order
+        /// they were written.
+        fields: Vec<(String, String)>,
+    },
 }
 
 /// First paragraph of a Dak commands file (`.dak-commands`) in order
 /// to prevent reuse between archives who have a shared developer.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "PascalCase"))]
 pub struct CommandHeader {
@@ -109,6 +140,7 @@ pub struct CommandHeader {
     pub archive: String,
 
     /// Optional identity of the individual uploading the command.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub uploader: Option<String>,
 }
 
@@ -119,6 +151,61 @@ mod _serde {
     use super::*;
     use std::io::{BufReader, Read};
 
+    /// [CommandAction]s this crate knows how to decode, tagged by their
+    /// `Action` field.
+    #[derive(Clone, Deserialize, Serialize)]
+    #[serde(tag = "Action")]
+    pub(super) enum KnownCommandAction {
+        #[serde(rename = "dm")]
+        Dm(Dm),
+
+        #[serde(rename = "break-the-archive")]
+        BreakTheArchive(BreakTheArchive),
+
+        #[serde(rename = "process-upload")]
+        ProcessUpload(ProcessUpload),
+    }
+
+    impl de::TaggedEnum for KnownCommandAction {
+        const TAG: &'static str = "Action";
+    }
+
+    impl From<de::Fallback<KnownCommandAction>> for CommandAction {
+        fn from(action: de::Fallback<KnownCommandAction>) -> Self {
+            match action {
+                de::Fallback::Known(KnownCommandAction::Dm(dm)) => Self::Dm(dm),
+                de::Fallback::Known(KnownCommandAction::BreakTheArchive(bta)) => {
+                    Self::BreakTheArchive(bta)
+                }
+                de::Fallback::Known(KnownCommandAction::ProcessUpload(pu)) => {
+                    Self::ProcessUpload(pu)
+                }
+                de::Fallback::Unknown { tag, fields } => Self::Unknown {
+                    action: tag,
+                    fields,
+                },
+            }
+        }
+    }
+
+    impl From<CommandAction> for de::Fallback<KnownCommandAction> {
+        fn from(action: CommandAction) -> Self {
+            match action {
+                CommandAction::Dm(dm) => Self::Known(KnownCommandAction::Dm(dm)),
+                CommandAction::BreakTheArchive(bta) => {
+                    Self::Known(KnownCommandAction::BreakTheArchive(bta))
+                }
+                CommandAction::ProcessUpload(pu) => {
+                    Self::Known(KnownCommandAction::ProcessUpload(pu))
+                }
+                CommandAction::Unknown { action, fields } => Self::Unknown {
+                    tag: action,
+                    fields,
+                },
+            }
+        }
+    }
+
     impl Command {
         /// Parse a [Command] from a [std::io::Read] traited object.
         pub fn from_reader<ReadT>(read: &mut BufReader<ReadT>) -> Result<Command, CommandError>
@@ -131,6 +218,17 @@ mod _serde {
                 actions: de::from_reader_iter(read).collect::<Result<Vec<_>, _>>()?,
             })
         }
+
+        /// Encode this [Command] as a `.dak-commands` file: the
+        /// [CommandHeader] paragraph followed by one paragraph per
+        /// [CommandAction].
+        pub fn to_string(&self) -> Result<String, CommandError> {
+            let mut paragraphs = vec![ser::to_string(&self.header)?];
+            for action in &self.actions {
+                paragraphs.push(ser::to_string(action)?);
+            }
+            Ok(paragraphs.join("\n"))
+        }
     }
 
     #[cfg(feature = "tokio")]
@@ -219,6 +317,52 @@ Deny: yet-another-package
             assert_eq!(&["yet-another-package"], &*dm.deny.unwrap());
         }
 
+        #[test]
+        fn command_unknown() {
+            let command: CommandAction = de::from_str(
+                "\
+Action: future-command
+Source: hello
+Reason:
+ Some reason
+ over two lines
+",
+            )
+            .unwrap();
+
+            let CommandAction::Unknown { action, fields } = command else {
+                panic!("not an unknown command");
+            };
+
+            assert_eq!("future-command", action);
+            assert_eq!(
+                vec![
+                    ("Source".to_owned(), "hello".to_owned()),
+                    (
+                        "Reason".to_owned(),
+                        "\nSome reason\nover two lines".to_owned()
+                    ),
+                ],
+                fields
+            );
+        }
+
+        #[test]
+        fn command_known_invalid() {
+            // known actions with bad fields must still fail, rather than
+            // become an Unknown.
+            assert!(
+                de::from_str::<CommandAction>(
+                    "\
+Action: process-upload
+Source: hello
+"
+                )
+                .is_err()
+            );
+            assert!(de::from_str::<CommandAction>("Source: hello\n").is_err());
+        }
+
         #[test]
         fn command_header() {
             let header: CommandHeader = de::from_str(
@@ -259,6 +403,66 @@ Deny: two
             assert_eq!(command.header.archive, "ftp.upload.debian.org");
             assert_eq!(2, command.actions.len());
         }
+
+        macro_rules! check_round_trip {
+            ($name:ident, $data:expr) => {
+                #[test]
+                fn $name() {
+                    let command =
+                        Command::from_reader(&mut BufReader::new(Cursor::new($data))).unwrap();
+                    let encoded = command.to_string().unwrap();
+                    assert_eq!($data, encoded);
+                    assert_eq!(
+                        command,
+                        Command::from_reader(&mut BufReader::new(Cursor::new(encoded))).unwrap()
+                    );
+                }
+            };
+        }
+
+        check_round_trip!(
+            round_trip_all_actions,
+            "\
+Archive: ftp.upload.debian.org
+Uploader: Paul Tagliamonte <paultag@debian.org>
+
+Action: dm
+Fingerprint: 1234567890ABCDEF1234567890ABCDEF
+Allow: one-package another-package
+Deny: yet-another-package
+
+Action: break-the-archive
+
+Action: process-upload
+Command: ACCEPT
+Source: foo
+Version: 1.0-1
+
+Action: dm
+Fingerprint: 1234567890ABCDEF1234567890ABCDEF
+Deny: two
+"
+        );
+
+        check_round_trip!(
+            round_trip_unknown,
+            "\
+Archive: ftp.upload.debian.org
+
+Action: future-command
+Source: hello
+Reason:
+ Some reason
+ over two lines
+"
+        );
+
+        check_round_trip!(
+            round_trip_fixture,
+            include_str!(
+                "../../../tests/generated-fixture-tests/fixtures/unsigned/dak/command/break-the-archive/simple.good"
+            )
+        );
     }
 }
 
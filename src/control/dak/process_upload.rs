@@ -36,7 +36,7 @@ pub enum ProcessUploadCommand {
 }
 
 /// Migrate or block a staged upload
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "PascalCase"))]
 pub struct ProcessUpload {
@@ -84,7 +84,7 @@ mod chrono {
 
     impl std::fmt::Display for DateTime2822 {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-            write!(f, "{}", self.0)
+            write!(f, "{}", self.0.format("%a, %d %b %Y %H:%M:%S %z"))
         }
     }
 
@@ -113,6 +113,14 @@ mod chrono {
                     .is_err()
             );
         }
+
+        #[test]
+        fn test_date_time_chrono_negative_offset() {
+            let when: DateTime2822 = "Wed, 04 Dec 2024 15:18:38 -0500".parse().unwrap();
+            assert_eq!(-5 * 3600, when.to_datetime().offset().local_minus_utc());
+            assert_eq!(1733343518, when.to_datetime().timestamp());
+            assert_eq!("Wed, 04 Dec 2024 15:18:38 -0500", when.to_string());
+        }
     }
 }
 
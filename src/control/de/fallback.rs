@@ -0,0 +1,221 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::{Error, from_fields};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+};
+use std::marker::PhantomData;
+
+/// Internally tagged enum (`#[serde(tag = "...")]`) which can be decoded
+/// with a catch-all for tags it doesn't know about, by way of [Fallback].
+pub trait TaggedEnum {
+    /// Name of the field holding the tag, which must match the enum's
+    /// `#[serde(tag = "...")]` attribute.
+    const TAG: &'static str;
+}
+
+/// Paragraph which is either decoded as the internally tagged enum `T`,
+/// or, if the value of the [TaggedEnum::TAG] field isn't a variant `T`
+/// knows about, kept as-is.
+///
+/// This allows for newer paragraphs (such as a new `Action` in a
+/// `.dak-commands` file) to be decoded by older code. Paragraphs with a
+/// known tag but invalid fields are still an error.
+///
+/// ```
+/// use deb::control::de::{self, Fallback, TaggedEnum};
+///
+/// #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+/// #[serde(tag = "Action")]
+/// enum Action {
+///     #[serde(rename = "hello")]
+///     Hello {
+///         #[serde(rename = "Name")]
+///         name: String,
+///     },
+/// }
+///
+/// impl TaggedEnum for Action {
+///     const TAG: &'static str = "Action";
+/// }
+///
+/// let action: Fallback<Action> = de::from_str("\
+/// Action: future-command
+/// Source: hello
+/// ").unwrap();
+/// assert_eq!(
+///     Fallback::Unknown {
+///         tag: "future-command".to_owned(),
+///         fields: vec![("Source".to_owned(), "hello".to_owned())],
+///     },
+///     action,
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fallback<T> {
+    /// Tag was known to `T`, and the paragraph was decoded as a `T`.
+    Known(T),
+
+    /// Tag wasn't known to `T`.
+    Unknown {
+        /// Value of the [TaggedEnum::TAG] field.
+        tag: String,
+
+        /// Every other field of the paragraph, in the order they were
+        /// written.
+        fields: Vec<(String, String)>,
+    },
+}
+
+/// Every field of a paragraph, in order.
+struct OrderedFields(Vec<(String, String)>);
+
+impl<'de> Deserialize<'de> for OrderedFields {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        struct OrderedFieldsVisitor(PhantomData<OrderedFields>);
+
+        impl<'de> Visitor<'de> for OrderedFieldsVisitor {
+            type Value = OrderedFields;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a paragraph")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<OrderedFields, A::Error> {
+                let mut fields = vec![];
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(OrderedFields(fields))
+            }
+        }
+
+        d.deserialize_map(OrderedFieldsVisitor(PhantomData))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Fallback<T>
+where
+    T: TaggedEnum + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let OrderedFields(mut fields) = OrderedFields::deserialize(d)?;
+        let Some(tag_idx) = fields.iter().position(|(key, _)| key == T::TAG) else {
+            return Err(de::Error::missing_field(T::TAG));
+        };
+
+        let known = from_fields::<T, _>(
+            fields
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        );
+        match known {
+            Ok(known) => Ok(Self::Known(known)),
+            Err(Error::UnknownVariant(variant)) if variant == fields[tag_idx].1 => {
+                let (_, tag) = fields.remove(tag_idx);
+                Ok(Self::Unknown { tag, fields })
+            }
+            Err(err) => Err(de::Error::custom(err)),
+        }
+    }
+}
+
+impl<T> Serialize for Fallback<T>
+where
+    T: TaggedEnum + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Known(known) => known.serialize(serializer),
+            Self::Unknown { tag, fields } => {
+                let mut map = serializer.serialize_map(Some(fields.len() + 1))?;
+                map.serialize_entry(T::TAG, tag)?;
+                for (key, value) in fields {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::{de::from_str, ser::to_string};
+
+    #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(tag = "Action")]
+    enum Action {
+        #[serde(rename = "hello")]
+        Hello {
+            #[serde(rename = "Name")]
+            name: String,
+        },
+    }
+
+    impl TaggedEnum for Action {
+        const TAG: &'static str = "Action";
+    }
+
+    #[test]
+    fn known() {
+        assert_eq!(
+            Fallback::Known(Action::Hello {
+                name: "World".to_owned()
+            }),
+            from_str::<Fallback<Action>>("Action: hello\nName: World\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn known_invalid() {
+        assert!(from_str::<Fallback<Action>>("Action: hello\n").is_err());
+        assert!(from_str::<Fallback<Action>>("Name: World\n").is_err());
+    }
+
+    #[test]
+    fn unknown_keeps_order() {
+        let data = "\
+Action: future-command
+Zebra: last
+Source: hello
+Apple: first
+";
+        let action = from_str::<Fallback<Action>>(data).unwrap();
+        assert_eq!(
+            Fallback::Unknown {
+                tag: "future-command".to_owned(),
+                fields: vec![
+                    ("Zebra".to_owned(), "last".to_owned()),
+                    ("Source".to_owned(), "hello".to_owned()),
+                    ("Apple".to_owned(), "first".to_owned()),
+                ],
+            },
+            action
+        );
+        assert_eq!(data, to_string(&action).unwrap());
+    }
+}
+
+// vim: foldmethod=marker
@@ -20,6 +20,14 @@
 
 //! Module to use the [serde] deserialization framework to decode data in
 //! Debian's RFC2822-like format to Rust types.
+//!
+//! # `Option` Fields
+//!
+//! A Field which is absent from the paragraph will deserialize to `None`.
+//! A Field which is present will always deserialize to `Some`, even if the
+//! value is empty or only whitespace -- in which case the inner type is
+//! parsed from the empty string (so an `Option<String>` becomes
+//! `Some("")`).
 
 #![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 
@@ -39,9 +47,12 @@ use sequoia_openpgp::{Cert, packet::Signature};
 #[cfg(feature = "sequoia")]
 use std::path::Path;
 
+mod fallback;
 mod outer;
 mod paragraph;
 
+pub use fallback::{Fallback, TaggedEnum};
+
 /// Error conditions which may be encountered during Deserialization
 #[derive(Debug)]
 pub enum Error {
@@ -81,6 +92,11 @@ pub enum Error {
     /// Somehow, against all odds, something managed to be invalid
     /// Utf-8, and was caught astonishingly late in the process.
     InvalidText(std::str::Utf8Error),
+
+    /// An enum tag (such as the `Action` of an internally tagged enum)
+    /// didn't match any of the enum's variants. See [Fallback] to decode
+    /// these rather than fail.
+    UnknownVariant(String),
 }
 crate::errors::error_enum!(Error);
 
@@ -97,6 +113,10 @@ impl de::Error for Error {
     {
         Self::De(custom.to_string())
     }
+
+    fn unknown_variant(variant: &str, _expected: &'static [&'static str]) -> Self {
+        Self::UnknownVariant(variant.to_owned())
+    }
 }
 
 /// Deserialize the provided Debian-flavored RFC2822 data into the desired
@@ -140,6 +160,32 @@ where
     }
 }
 
+/// Deserialize the first Debian-flavored RFC2822 paragraph from a
+/// [std::io::Read] into the desired Rust type, wrapping it in a
+/// [BufReader] internally.
+///
+/// Since the [BufReader] is dropped when this returns, anything it read
+/// beyond the first paragraph is lost. If you want to read more than one
+/// paragraph, use [from_reader] or [from_reader_iter] with a [BufReader]
+/// of your own.
+///
+/// ```no_run
+/// use deb::control::{de, package::Changes};
+/// use std::fs::File;
+///
+/// let changes: Changes =
+///     de::from_unbuffered_reader(File::open("hello_2.10-3_amd64.changes").unwrap()).unwrap();
+///
+/// println!("{:?}", changes);
+/// ```
+pub fn from_unbuffered_reader<'de, T, ReadT>(input: ReadT) -> Result<T, Error>
+where
+    ReadT: Read,
+    T: de::Deserialize<'de>,
+{
+    from_reader(&mut BufReader::new(input))
+}
+
 struct ControlIterator<'a, 'de, T, ReadT> {
     input: &'a mut BufReader<ReadT>,
     _de: PhantomData<&'de ()>,
@@ -176,6 +222,40 @@ where
     }
 }
 
+/// Deserialize every paragraph from the provided reader, carrying on past
+/// paragraphs which fail to decode rather than stopping at the first one.
+///
+/// This returns every value which was decoded, along with every error and
+/// the (zero-based) index of the paragraph it came from, which is helpful
+/// when validating a large file (like a `Packages` index) where it's more
+/// useful to see all the problems at once.
+///
+/// An [Error::Io] can't be recovered from, so it will be the last error
+/// returned, and no further paragraphs are read.
+pub fn from_reader_iter_collecting<'de, T, ReadT>(
+    input: &mut BufReader<ReadT>,
+) -> (Vec<T>, Vec<(usize, Error)>)
+where
+    ReadT: Read,
+    T: de::Deserialize<'de>,
+{
+    let mut values = vec![];
+    let mut errors = vec![];
+
+    for (idx, value) in from_reader_iter(input).enumerate() {
+        match value {
+            Ok(value) => values.push(value),
+            Err(err @ Error::Io(_)) => {
+                errors.push((idx, err));
+                break;
+            }
+            Err(err) => errors.push((idx, err)),
+        }
+    }
+
+    (values, errors)
+}
+
 #[cfg(feature = "tokio")]
 mod _tokio {
     #![cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
@@ -405,15 +485,63 @@ where
     from_raw_paragraph(&rp)
 }
 
+/// Deserialize the desired Rust type from the fields of a paragraph which
+/// has already been split into keys and values, such as a paragraph which
+/// was first captured into a `BTreeMap<String, String>`.
+///
+/// This is helpful when implementing [serde::Deserialize] for a type which
+/// needs to look at a paragraph before deciding how to decode it, such as
+/// an enum tagged by one of its fields, which should keep any tag it
+/// doesn't know about (and the rest of the paragraph) rather than fail.
+/// The values are handled exactly as if they came from the paragraph
+/// directly, so they should be as they were decoded as a [String].
+///
+/// ```
+/// use deb::control::de;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Test {
+///     #[serde(rename = "Hello")]
+///     hello: String,
+///     #[serde(rename = "Number")]
+///     number: u32,
+/// }
+///
+/// let fields: BTreeMap<String, String> = de::from_str("\
+/// Hello: World
+/// Number: 42
+/// ").unwrap();
+///
+/// let test: Test = de::from_fields(
+///     fields.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+/// ).unwrap();
+/// assert_eq!("World", test.hello);
+/// assert_eq!(42, test.number);
+/// ```
+pub fn from_fields<'a, 'de, T, IterT>(fields: IterT) -> Result<T, Error>
+where
+    IterT: IntoIterator<Item = (&'a str, &'a str)>,
+    IterT::IntoIter: Clone,
+    T: de::Deserialize<'de>,
+{
+    let iter = fields
+        .into_iter()
+        .flat_map(|(key, value)| [key, value])
+        .peekable();
+    let mut deserializer = outer::Deserializer { iter };
+    T::deserialize(&mut deserializer)
+}
+
 /// Decode from a [RawParagraph]
-fn from_raw_paragraph<'a, 'de, T>(input: &'a RawParagraph) -> Result<T, Error>
+fn from_raw_paragraph<'a, 'de, T>(input: &'a RawParagraph<'_>) -> Result<T, Error>
 where
     T: de::Deserialize<'de>,
 {
     let iter = input
         .fields
         .iter()
-        .flat_map(|v| [v.key.as_str(), v.value.as_str()])
+        .flat_map(|v| [v.key.as_ref(), v.value.as_ref()])
         .peekable();
     let mut deserializer = outer::Deserializer { iter };
     T::deserialize(&mut deserializer)
@@ -526,6 +654,35 @@ Ello: something
         assert!(test.ello.is_some());
     }
 
+    #[derive(Clone, Debug, PartialEq, Deserialize)]
+    struct TestOptional {
+        #[serde(rename = "Package")]
+        package: String,
+
+        #[serde(rename = "Ello")]
+        ello: Option<String>,
+    }
+
+    macro_rules! check_optional {
+        ($name:ident, $input:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let test: TestOptional = from_str($input).unwrap();
+                assert_eq!($expected, test.ello.as_deref());
+            }
+        };
+    }
+
+    check_optional!(test_option_absent, "Package: foo\n", None);
+    check_optional!(
+        test_option_present,
+        "Package: foo\nEllo: bar\n",
+        Some("bar")
+    );
+    check_optional!(test_option_empty, "Package: foo\nEllo:\n", Some(""));
+    check_optional!(test_option_whitespace, "Package: foo\nEllo:   \n", Some(""));
+    check_optional!(test_option_empty_first, "Ello:\nPackage: foo\n", Some(""));
+
     #[test]
     fn test_reader() {
         assert!(from_reader::<TestControlFile, _>(&mut BufReader::new(Cursor::new(""))).is_err());
@@ -759,6 +916,24 @@ Hello: World
         assert_eq!(test.hello, "World");
     }
 
+    #[test]
+    fn test_from_unbuffered_reader() {
+        let test: TestControl = from_unbuffered_reader(Cursor::new(
+            "\
+Hello: World
+
+Hello: Paul
+",
+        ))
+        .unwrap();
+        assert_eq!(test.hello, "World");
+
+        assert!(matches!(
+            from_unbuffered_reader::<TestControl, _>(Cursor::new("\n\n")),
+            Err(Error::EndOfFile)
+        ));
+    }
+
     #[test]
     fn test_from_reader_iter() {
         let mut reader = BufReader::new(Cursor::new(
@@ -778,6 +953,44 @@ Hello: Me
             .collect::<Vec<_>>();
         assert_eq!(vec!["World", "Paul", "You", "Me"], values);
     }
+
+    #[test]
+    fn test_from_reader_iter_collecting() {
+        let mut reader = BufReader::new(Cursor::new(
+            "\
+Hello: World
+
+Goodbye: Paul
+
+Hello: You
+
+Hello: Me
+Hello: Again
+
+Hello: Me
+",
+        ));
+
+        let (values, errors) = from_reader_iter_collecting::<TestControl, _>(&mut reader);
+        assert_eq!(
+            vec!["World", "You", "Me"],
+            values.into_iter().map(|v| v.hello).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1, 3],
+            errors.iter().map(|(idx, _)| *idx).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_from_reader_iter_collecting_io() {
+        let mut reader = BufReader::new(Cursor::new(b"Hello: World\n\nHello: \xff\n\nHello: Me\n"));
+
+        let (values, errors) = from_reader_iter_collecting::<TestControl, _>(&mut reader);
+        assert_eq!(1, values.len());
+        assert_eq!(1, errors.len());
+        assert!(matches!(errors[0], (1, Error::Io(_))));
+    }
 }
 
 // vim: foldmethod=marker
@@ -46,6 +46,97 @@ impl<const DELIM: char, InnerT> Delimited<DELIM, InnerT> {
     }
 }
 
+/// Repeated values delimited with some char `DELIM`, where each entry is
+/// trimmed and empty entries (such as from a trailing `DELIM`) are
+/// skipped. Unlike [Delimited], which rejects `amd64  arm64` as containing
+/// an empty entry, this is lenient about how the list is spaced out.
+///
+/// When `DELIM` is `' '`, any ASCII whitespace separates entries.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct DelimitedSeq<const DELIM: char, InnerT>(pub Vec<InnerT>);
+
+/// Repeated values separated by any amount of ASCII whitespace on a single
+/// line, such as `Architecture: amd64 arm64 source`.
+pub type SpaceDelimitedSeq<InnerT> = DelimitedSeq<' ', InnerT>;
+
+/// Repeated values separated by commas, such as
+/// `Testsuite: autopkgtest, autopkgtest-pkg-perl`.
+pub type CommaDelimitedSeq<InnerT> = DelimitedSeq<',', InnerT>;
+
+impl<const DELIM: char, InnerT> DelimitedSeq<DELIM, InnerT> {
+    /// Get the inner value as a ref
+    pub fn get_ref(&self) -> &[InnerT] {
+        &self.0
+    }
+}
+
+impl<const DELIM: char, InnerT> Deref for DelimitedSeq<DELIM, InnerT> {
+    type Target = [InnerT];
+    fn deref(&self) -> &[InnerT] {
+        &self.0
+    }
+}
+
+impl<const DELIM: char, InnerT> std::fmt::Display for DelimitedSeq<DELIM, InnerT>
+where
+    InnerT: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let sep = if DELIM == ' ' {
+            " ".to_owned()
+        } else {
+            format!("{DELIM} ")
+        };
+        write!(
+            f,
+            "{}",
+            &self
+                .0
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(&sep)
+        )
+    }
+}
+
+impl<const DELIM: char, InnerT> FromStr for DelimitedSeq<DELIM, InnerT>
+where
+    InnerT: FromStr,
+    InnerT::Err: std::fmt::Debug,
+{
+    type Err = InnerT::Err;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            input
+                .split(|c: char| c == DELIM || (DELIM == ' ' && c.is_ascii_whitespace()))
+                .map(|token| token.trim())
+                .filter(|token| !token.is_empty())
+                .map(|token| token.parse::<InnerT>())
+                .collect::<Result<Vec<InnerT>, _>>()?,
+        ))
+    }
+}
+
+impl<const DELIM: char> DelimitedStrings<DELIM> {
+    /// Return a copy of these values sorted, with duplicate and empty
+    /// entries removed, the same way `wrap-and-sort` would leave fields
+    /// like `Binary` or `Built-For-Profiles`.
+    pub fn sorted_dedup(&self) -> Self {
+        let mut values = self
+            .0
+            .iter()
+            .filter(|value| !value.is_empty())
+            .cloned()
+            .collect::<Vec<_>>();
+        values.sort();
+        values.dedup();
+        Self(values)
+    }
+}
+
 impl<const DELIM: char, InnerT> Deref for Delimited<DELIM, InnerT> {
     type Target = [InnerT];
     fn deref(&self) -> &[InnerT] {
@@ -86,7 +177,7 @@ where
         Ok(Self(
             input
                 .split(DELIM)
-                .map(|closes| closes.parse::<InnerT>())
+                .map(|closes| closes.trim().parse::<InnerT>())
                 .collect::<Result<Vec<InnerT>, _>>()?,
         ))
     }
@@ -94,7 +185,7 @@ where
 
 #[cfg(feature = "serde")]
 mod serde {
-    use super::Delimited;
+    use super::{Delimited, DelimitedSeq};
     use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
 
     impl<const DELIM: char, InnerT> Serialize for Delimited<DELIM, InnerT>
@@ -119,11 +210,38 @@ mod serde {
             s.parse().map_err(|e| D::Error::custom(format!("{e:?}")))
         }
     }
+
+    impl<const DELIM: char, InnerT> Serialize for DelimitedSeq<DELIM, InnerT>
+    where
+        InnerT: std::fmt::Display,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            String::serialize(&self.to_string(), serializer)
+        }
+    }
+
+    impl<'de, const DELIM: char, InnerT> Deserialize<'de> for DelimitedSeq<DELIM, InnerT>
+    where
+        InnerT: std::str::FromStr,
+        InnerT::Err: std::fmt::Debug,
+    {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let s = String::deserialize(d)?;
+            s.parse().map_err(|e| D::Error::custom(format!("{e:?}")))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{CommaDelimitedStrings, SpaceDelimitedStrings};
+    use super::{
+        CommaDelimitedSeq, CommaDelimitedStrings, DelimitedSeq, SpaceDelimitedSeq,
+        SpaceDelimitedStrings,
+    };
+    use crate::architecture::{self, Architecture};
     use crate::control::{Delimited, def_parse_test};
 
     def_parse_test!(
@@ -161,12 +279,125 @@ mod tests {
         Delimited::<' ', String>(vec!["foo".to_owned(), "".to_owned(), "bar".to_owned()])
     );
 
+    def_parse_test!(
+        comma_parse_spaces,
+        CommaDelimitedStrings,
+        "foo, bar ,baz",
+        Delimited::<',', String>(vec!["foo".to_owned(), "bar".to_owned(), "baz".to_owned()])
+    );
+
     def_parse_test!(
         comma_parse_extra,
         CommaDelimitedStrings,
         "foo,,bar",
         Delimited::<',', String>(vec!["foo".to_owned(), "".to_owned(), "bar".to_owned()])
     );
+
+    #[test]
+    fn space_sorted_dedup() {
+        let binary: SpaceDelimitedStrings = "hello-dbgsym hello  hello-doc hello hello-dbgsym"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            "hello hello-dbgsym hello-doc",
+            binary.sorted_dedup().to_string()
+        );
+    }
+
+    #[test]
+    fn comma_sorted_dedup() {
+        let values: CommaDelimitedStrings = "zsh, bash,, dash, bash".parse().unwrap();
+        assert_eq!("bash,dash,zsh", values.sorted_dedup().to_string());
+    }
+
+    #[test]
+    fn sorted_dedup_empty() {
+        let values: SpaceDelimitedStrings = "".parse().unwrap();
+        assert!(values.sorted_dedup().is_empty());
+    }
+
+    def_parse_test!(
+        seq_parse_empty,
+        SpaceDelimitedSeq<Architecture>,
+        "",
+        DelimitedSeq(vec![])
+    );
+
+    def_parse_test!(
+        seq_parse_whitespace,
+        SpaceDelimitedSeq<Architecture>,
+        " amd64  arm64\tsource ",
+        DelimitedSeq(vec![
+            architecture::AMD64,
+            architecture::ARM64,
+            architecture::SOURCE
+        ])
+    );
+
+    #[test]
+    fn seq_parse_bad_token() {
+        assert!(
+            "amd64 linux-"
+                .parse::<SpaceDelimitedSeq<Architecture>>()
+                .is_err()
+        );
+    }
+
+    def_parse_test!(
+        comma_seq_parse_empty,
+        CommaDelimitedSeq<Architecture>,
+        "",
+        DelimitedSeq(vec![])
+    );
+
+    def_parse_test!(
+        comma_seq_parse_extra,
+        CommaDelimitedSeq<Architecture>,
+        " amd64,, arm64,\n source, ",
+        DelimitedSeq(vec![
+            architecture::AMD64,
+            architecture::ARM64,
+            architecture::SOURCE
+        ])
+    );
+
+    #[test]
+    fn comma_seq_display() {
+        let arches: CommaDelimitedSeq<Architecture> = "amd64,arm64,".parse().unwrap();
+        assert_eq!("amd64, arm64", arches.to_string());
+    }
+
+    #[test]
+    fn seq_display() {
+        let arches: SpaceDelimitedSeq<Architecture> = "amd64   arm64".parse().unwrap();
+        assert_eq!("amd64 arm64", arches.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use crate::control::de;
+        use ::serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Test {
+            architecture: SpaceDelimitedSeq<Architecture>,
+        }
+
+        #[test]
+        fn seq_deserialize() {
+            let test: Test = de::from_str("Architecture: amd64 arm64 source\n").unwrap();
+            assert_eq!(
+                &[
+                    architecture::AMD64,
+                    architecture::ARM64,
+                    architecture::SOURCE
+                ],
+                &test.architecture[..]
+            );
+        }
+    }
 }
 
 // vim: foldmethod=marker
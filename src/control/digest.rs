@@ -32,9 +32,31 @@ const HASH_LEN_SHA256: usize = 32;
 const HASH_LEN_SHA512: usize = 64;
 
 /// [Digest] is a specific File's hash digest.
+///
+/// The [PartialEq] implementation will return as soon as a difference is
+/// found, so it may take a variable amount of time. When checking a digest
+/// for security sensitive verification, use `Digest::ct_eq` (from the
+/// `subtle` feature) instead.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Digest<const HASH_LEN: usize>(InnerDateTime<HASH_LEN>);
 
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+impl<const HASH_LEN: usize> Digest<HASH_LEN> {
+    /// Compare two [Digest] values in constant time, rather than returning
+    /// at the first byte that differs like [PartialEq] does. This should be
+    /// used when comparing a computed digest against an expected one, to
+    /// avoid leaking how much of the digest matched through timing.
+    ///
+    /// The comparison is done by [subtle::ConstantTimeEq], which takes care
+    /// to keep the optimizer from adding an early exit.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let lhs: &[u8] = self.0.as_ref();
+        let rhs: &[u8] = other.0.as_ref();
+        subtle::ConstantTimeEq::ct_eq(lhs, rhs).into()
+    }
+}
+
 /// [Digest] for the MD5 digest algorithm.
 ///
 /// This entry contains a now very antiquated `md5` digest, which should
@@ -190,6 +212,28 @@ mod tests {
     check_fails!(bad_sha256, DigestSha256: "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b85");
     check_fails!(bad_sha512, DigestSha512: "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3");
 
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn ct_eq() {
+        let empty: DigestSha256 =
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+                .parse()
+                .unwrap();
+        let other: DigestSha256 =
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b856"
+                .parse()
+                .unwrap();
+
+        assert!(empty.ct_eq(&empty.clone()));
+        assert!(!empty.ct_eq(&other));
+        assert!(!other.ct_eq(&empty));
+
+        let empty: DigestMd5 = "d41d8cd98f00b204e9800998ecf8427e".parse().unwrap();
+        let other: DigestMd5 = "c41d8cd98f00b204e9800998ecf8427e".parse().unwrap();
+        assert!(empty.ct_eq(&empty.clone()));
+        assert!(!empty.ct_eq(&other));
+    }
+
     #[cfg(feature = "hex")]
     mod hex {
         use super::*;
@@ -0,0 +1,311 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::def_serde_traits_for;
+use std::str::FromStr;
+
+/// Person responsible for a package, as found in the `Maintainer` or
+/// `Changed-By` fields, or one of the `Uploaders`.
+///
+/// This is an RFC 822 style address, usually in the form of
+/// `Name <email>`. The name may be quoted (which is required if it contains
+/// a comma, such as `"Vila, Santiago" <sanvila@debian.org>`), and
+/// parenthesized comments are allowed anywhere.
+///
+/// ```
+/// use deb::control::Maintainer;
+///
+/// let maintainer: Maintainer = "\"Vila, Santiago\" <sanvila@debian.org>".parse().unwrap();
+/// assert_eq!(Some("Vila, Santiago"), maintainer.name.as_deref());
+/// assert_eq!("sanvila@debian.org", maintainer.email);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Maintainer {
+    /// Display name of the person, without any quoting or comments. If
+    /// there is no display name, but there is a comment (as in the older
+    /// `sanvila@debian.org (Santiago Vila)` style), the comment is used.
+    pub name: Option<String>,
+
+    /// Email address of the person.
+    pub email: String,
+}
+
+def_serde_traits_for!(Maintainer);
+
+/// Error conditions which may be encountered when parsing a [Maintainer].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MaintainerParseError {
+    /// The input was empty.
+    Empty,
+
+    /// A quoted name was opened, but never closed.
+    UnterminatedQuote,
+
+    /// A parenthesized comment was opened, but never closed.
+    UnterminatedComment,
+
+    /// An `<` was found without a closing `>`, or there was more than one
+    /// `<email>`.
+    MalformedAddress,
+
+    /// No email address was found, or it isn't of the form `local@domain`.
+    InvalidEmail,
+}
+crate::errors::error_enum!(MaintainerParseError);
+
+/// Chars which require the name to be quoted when written out.
+const SPECIALS: &[char] = &['(', ')', '<', '>', '@', ',', ';', ':', '\\', '"', '[', ']'];
+
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+    !local.is_empty() && !domain.is_empty() && !email.contains(char::is_whitespace)
+}
+
+impl FromStr for Maintainer {
+    type Err = MaintainerParseError;
+
+    fn from_str(maintainer: &str) -> Result<Self, Self::Err> {
+        let maintainer = maintainer.trim();
+        if maintainer.is_empty() {
+            return Err(MaintainerParseError::Empty);
+        }
+
+        let mut phrase = String::new();
+        let mut comments: Vec<String> = vec![];
+        let mut address: Option<String> = None;
+
+        let mut chars = maintainer.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '"' => loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => phrase.push(
+                            chars
+                                .next()
+                                .ok_or(MaintainerParseError::UnterminatedQuote)?,
+                        ),
+                        Some(ch) => phrase.push(ch),
+                        None => return Err(MaintainerParseError::UnterminatedQuote),
+                    }
+                },
+                '(' => {
+                    let mut comment = String::new();
+                    let mut depth = 1;
+                    loop {
+                        match chars.next() {
+                            Some('(') => {
+                                depth += 1;
+                                comment.push('(');
+                            }
+                            Some(')') => {
+                                depth -= 1;
+                                if depth == 0 {
+                                    break;
+                                }
+                                comment.push(')');
+                            }
+                            Some('\\') => comment.push(
+                                chars
+                                    .next()
+                                    .ok_or(MaintainerParseError::UnterminatedComment)?,
+                            ),
+                            Some(ch) => comment.push(ch),
+                            None => return Err(MaintainerParseError::UnterminatedComment),
+                        }
+                    }
+                    // A comment separates words just like whitespace.
+                    phrase.push(' ');
+                    comments.push(comment.trim().to_owned());
+                }
+                '<' => {
+                    if address.is_some() {
+                        return Err(MaintainerParseError::MalformedAddress);
+                    }
+                    let mut email = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('>') => break,
+                            Some(ch) => email.push(ch),
+                            None => return Err(MaintainerParseError::MalformedAddress),
+                        }
+                    }
+                    address = Some(email.trim().to_owned());
+                }
+                '>' => return Err(MaintainerParseError::MalformedAddress),
+                ch => phrase.push(ch),
+            }
+        }
+
+        let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+        let comment = comments.into_iter().find(|comment| !comment.is_empty());
+
+        let (name, email) = match address {
+            Some(email) => (
+                if phrase.is_empty() {
+                    comment
+                } else {
+                    Some(phrase)
+                },
+                email,
+            ),
+            None => (comment, phrase),
+        };
+
+        if !is_valid_email(&email) {
+            return Err(MaintainerParseError::InvalidEmail);
+        }
+
+        Ok(Maintainer { name, email })
+    }
+}
+
+impl std::fmt::Display for Maintainer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match &self.name {
+            Some(name) if name.contains(SPECIALS) => write!(
+                f,
+                "\"{}\" <{}>",
+                name.replace('\\', "\\\\").replace('"', "\\\""),
+                self.email
+            ),
+            Some(name) => write!(f, "{} <{}>", name, self.email),
+            None => write!(f, "{}", self.email),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! check_parses {
+        ($name:ident, $maintainer:expr, $display_name:expr, $email:expr) => {
+            #[test]
+            fn $name() {
+                let maintainer: Maintainer = $maintainer.parse().unwrap();
+                assert_eq!(
+                    $display_name.map(|name: &str| name.to_owned()),
+                    maintainer.name
+                );
+                assert_eq!($email, maintainer.email);
+            }
+        };
+    }
+
+    macro_rules! check_fails {
+        ($name:ident, $maintainer:expr, $err:expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(Err($err), $maintainer.parse::<Maintainer>());
+            }
+        };
+    }
+
+    check_parses!(
+        simple,
+        "Santiago Vila <sanvila@debian.org>",
+        Some("Santiago Vila"),
+        "sanvila@debian.org"
+    );
+    check_parses!(
+        quoted_comma,
+        "\"Vila, Santiago\" <sanvila@debian.org>",
+        Some("Vila, Santiago"),
+        "sanvila@debian.org"
+    );
+    check_parses!(
+        quoted_escape,
+        r#""Santiago \"sanvila\" Vila" <sanvila@debian.org>"#,
+        Some("Santiago \"sanvila\" Vila"),
+        "sanvila@debian.org"
+    );
+    check_parses!(bare_email, "sanvila@debian.org", None, "sanvila@debian.org");
+    check_parses!(
+        bare_email_comment,
+        "sanvila@debian.org (Santiago Vila)",
+        Some("Santiago Vila"),
+        "sanvila@debian.org"
+    );
+    check_parses!(
+        name_comment,
+        "Debian Go Packaging Team (team) <team+pkg-go@tracker.debian.org>",
+        Some("Debian Go Packaging Team"),
+        "team+pkg-go@tracker.debian.org"
+    );
+    check_parses!(
+        nested_comment,
+        "sanvila@debian.org (Santiago (sanvila) Vila)",
+        Some("Santiago (sanvila) Vila"),
+        "sanvila@debian.org"
+    );
+    check_parses!(
+        only_address,
+        "<sanvila@debian.org>",
+        None,
+        "sanvila@debian.org"
+    );
+
+    check_fails!(empty, "", MaintainerParseError::Empty);
+    check_fails!(
+        unterminated_quote,
+        "\"Vila, Santiago <sanvila@debian.org>",
+        MaintainerParseError::UnterminatedQuote
+    );
+    check_fails!(
+        unterminated_comment,
+        "sanvila@debian.org (Santiago",
+        MaintainerParseError::UnterminatedComment
+    );
+    check_fails!(
+        unterminated_address,
+        "Santiago Vila <sanvila@debian.org",
+        MaintainerParseError::MalformedAddress
+    );
+    check_fails!(
+        no_email,
+        "Santiago Vila",
+        MaintainerParseError::InvalidEmail
+    );
+    check_fails!(
+        empty_email,
+        "Santiago Vila <>",
+        MaintainerParseError::InvalidEmail
+    );
+
+    #[test]
+    fn round_trip() {
+        for maintainer in [
+            "Santiago Vila <sanvila@debian.org>",
+            "\"Vila, Santiago\" <sanvila@debian.org>",
+            r#""Santiago \"sanvila\" Vila" <sanvila@debian.org>"#,
+            "sanvila@debian.org",
+        ] {
+            assert_eq!(
+                maintainer,
+                maintainer.parse::<Maintainer>().unwrap().to_string()
+            );
+        }
+    }
+}
+
+// vim: foldmethod=marker
@@ -30,7 +30,10 @@
 //! | Binary Archive Release  | `dists/*/InRelease`                            | [archive::Release]       |
 //! | Binary Archive Index    | `dists/*/*/binary-*/Packages*`                 | [archive::Package]       |
 //! | `apt` `sources.list`    | `/etc/apt/sources.list/*.sources`              | [apt::SourcesList]       |
+//! | `apt` pinning           | `/etc/apt/preferences.d/*`                     | [apt::Preferences]       |
 //! | `dak` command           | `*.dak-commands`                               | [dak::Command]           |
+//! | `dpkg` status database  | `/var/lib/dpkg/status`                         | [status::StatusEntry]    |
+//! | Upstream Release Watch  | `debian/watch`                                 | [watch::Watch]           |
 //!
 //! # Feature `serde`
 //!
@@ -71,7 +74,9 @@
 //! This crate treats `simple` and `folded` fields as the same. This may
 //! break with a strict intepreation of control files, but it is only more
 //! lax in what it produces. This may change in the future, do not rely
-//! on this behavior.
+//! on this behavior. If you want `simple` fields spread over multiple lines
+//! to be rejected, use [RawParagraph::parse_with_options] with
+//! [ParseOptions::strict_field_types] set.
 //!
 //! `multiline` fields will be treated as a `simple` or `folded` field if
 //! they're unpacked into most fields, with the exception of unpacking a
@@ -97,21 +102,28 @@
 //! [tokio::io::AsyncRead], via [de::from_reader_async]
 
 mod architectures;
+#[cfg(feature = "serde")]
+mod as_sequence;
 mod date_time;
 mod delimited;
 mod digest;
 mod file_digest;
 mod macros;
+mod maintainer;
 mod number;
 mod paragraph;
 mod pest;
 mod priority;
+mod uploaders;
+mod wrap_and_sort;
 
 pub mod apt;
 pub mod archive;
 pub mod dak;
 pub mod package;
 pub mod queued;
+pub mod status;
+pub mod watch;
 
 #[cfg(feature = "serde")]
 pub mod de;
@@ -123,16 +135,24 @@ pub mod ser;
 mod openpgp;
 
 pub use architectures::Architectures;
+#[cfg(feature = "serde")]
+pub use as_sequence::AsSequence;
 pub use date_time::{DateTime2822, DateTime2822ParseError};
-pub use delimited::{CommaDelimitedStrings, Delimited, DelimitedStrings, SpaceDelimitedStrings};
+pub use delimited::{
+    CommaDelimitedSeq, CommaDelimitedStrings, Delimited, DelimitedSeq, DelimitedStrings,
+    SpaceDelimitedSeq, SpaceDelimitedStrings,
+};
 pub use digest::{Digest, DigestMd5, DigestParseError, DigestSha1, DigestSha256, DigestSha512};
 pub use file_digest::{
     FileDigest, FileDigestMd5, FileDigestParseError, FileDigestSha1, FileDigestSha256,
     FileDigestSha512,
 };
+pub use maintainer::{Maintainer, MaintainerParseError};
 pub use number::Number;
-pub use paragraph::{Error, RawField, RawParagraph};
+pub use paragraph::{Error, ParseOptions, RawField, RawParagraph};
 pub use priority::{Priority, PriorityParseError};
+pub use uploaders::Uploaders;
+pub use wrap_and_sort::WrapOpts;
 
 use macros::def_serde_traits_for;
 
@@ -140,6 +160,8 @@ use macros::def_serde_traits_for;
 use macros::{def_failing_parse_test, def_parse_test};
 
 #[cfg(feature = "sequoia")]
-pub use openpgp::{OpenPgpValidator, OpenPgpValidatorBuilder, OpenPgpValidatorError};
+pub use openpgp::{
+    OpenPgpValidator, OpenPgpValidatorBuilder, OpenPgpValidatorError, keyring_fingerprints,
+};
 
 // vim: foldmethod=marker
@@ -29,7 +29,10 @@ use sequoia_openpgp::{
     packet::Signature,
     parse::{
         Parse,
-        stream::{MessageLayer, MessageStructure, VerificationHelper, VerifierBuilder},
+        stream::{
+            DetachedVerifierBuilder, MessageLayer, MessageStructure, VerificationHelper,
+            VerifierBuilder,
+        },
     },
     policy::StandardPolicy,
 };
@@ -44,6 +47,7 @@ use std::{
 #[derive(Clone, Default)]
 pub struct OpenPgpValidatorBuilder {
     keyrings: Vec<PathBuf>,
+    certs: Vec<Cert>,
     insecure_skip_verify: bool,
 }
 
@@ -75,6 +79,47 @@ crate::errors::error_enum!(OpenPgpValidatorError);
 /// Wrapper type for a `Vec` of [Cert] and [Signature].
 pub type Signatures = Vec<(Cert, Signature)>;
 
+/// [VerificationHelper] which collects each good signature made by a key
+/// known to the [OpenPgpValidator].
+struct Helper<'a> {
+    validator: &'a OpenPgpValidator,
+    results: Signatures,
+}
+
+impl VerificationHelper for &mut Helper<'_> {
+    fn get_certs(&mut self, _ids: &[KeyHandle]) -> SequoiaResult<Vec<Cert>> {
+        Ok(self.validator.keys.values().cloned().collect())
+    }
+
+    fn check(&mut self, structure: MessageStructure) -> SequoiaResult<()> {
+        for (i, layer) in structure.into_iter().enumerate() {
+            match layer {
+                MessageLayer::Encryption { .. } if i == 0 => (),
+                MessageLayer::Compression { .. } if i == 1 => (),
+                MessageLayer::SignatureGroup { results } => {
+                    for result in results {
+                        let Ok(result) = result else {
+                            continue;
+                        };
+
+                        let signature = result.sig.clone();
+                        let fingerprints = signature.issuer_fingerprints();
+
+                        for fingerprint in fingerprints {
+                            let Some(signer) = self.validator.keys.get(fingerprint) else {
+                                continue;
+                            };
+                            self.results.push((signer.clone(), signature.clone()));
+                        }
+                    }
+                }
+                _ => return Err(anyhow::anyhow!("Unexpected message structure")),
+            }
+        }
+        Ok(())
+    }
+}
+
 impl OpenPgpValidator {
     /// Return a new [OpenPgpValidatorBuilder].
     pub fn build() -> OpenPgpValidatorBuilder {
@@ -115,45 +160,6 @@ impl OpenPgpValidator {
     ) -> Result<(Signatures, Cursor<Vec<u8>>), OpenPgpValidatorError> {
         let p = &StandardPolicy::new();
 
-        struct Helper<'a> {
-            validator: &'a OpenPgpValidator,
-            results: Signatures,
-        }
-
-        impl VerificationHelper for &mut Helper<'_> {
-            fn get_certs(&mut self, _ids: &[KeyHandle]) -> SequoiaResult<Vec<Cert>> {
-                Ok(self.validator.keys.values().cloned().collect())
-            }
-
-            fn check(&mut self, structure: MessageStructure) -> SequoiaResult<()> {
-                for (i, layer) in structure.into_iter().enumerate() {
-                    match layer {
-                        MessageLayer::Encryption { .. } if i == 0 => (),
-                        MessageLayer::Compression { .. } if i == 1 => (),
-                        MessageLayer::SignatureGroup { results } => {
-                            for result in results {
-                                let Ok(result) = result else {
-                                    continue;
-                                };
-
-                                let signature = result.sig.clone();
-                                let fingerprints = signature.issuer_fingerprints();
-
-                                for fingerprint in fingerprints {
-                                    let Some(signer) = self.validator.keys.get(fingerprint) else {
-                                        continue;
-                                    };
-                                    self.results.push((signer.clone(), signature.clone()));
-                                }
-                            }
-                        }
-                        _ => return Err(anyhow::anyhow!("Unexpected message structure")),
-                    }
-                }
-                Ok(())
-            }
-        }
-
         let mut helper = Helper {
             validator: self,
             results: vec![],
@@ -176,6 +182,40 @@ impl OpenPgpValidator {
 
         Ok((results, Cursor::new(content)))
     }
+
+    /// Check the detached OpenPGP `signature` over `message`, such as a
+    /// `Release` file and its `Release.gpg`, and return the valid
+    /// signatures.
+    ///
+    /// Like [Self::validate], this will return an error if none of the
+    /// signatures were made by a key in the keyring(s).
+    pub fn validate_detached(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<Signatures, OpenPgpValidatorError> {
+        let p = &StandardPolicy::new();
+
+        let mut helper = Helper {
+            validator: self,
+            results: vec![],
+        };
+
+        DetachedVerifierBuilder::from_bytes(signature)
+            .map_err(OpenPgpValidatorError::Sequoia)?
+            .with_policy(p, None, &mut helper)
+            .map_err(OpenPgpValidatorError::Sequoia)?
+            .verify_bytes(message)
+            .map_err(OpenPgpValidatorError::Sequoia)?;
+
+        let Helper { results, .. } = helper;
+
+        if results.is_empty() && !self.insecure_skip_verify {
+            return Err(OpenPgpValidatorError::NoValidSignatures);
+        }
+
+        Ok(results)
+    }
 }
 
 #[cfg(feature = "tokio")]
@@ -214,6 +254,14 @@ impl OpenPgpValidatorBuilder {
         self
     }
 
+    /// Use the provided [Cert], which has already been loaded. This will
+    /// append the [Cert] to the set of authorized keys, along with any
+    /// keyrings.
+    pub fn with_cert(mut self, cert: Cert) -> Self {
+        self.certs.push(cert);
+        self
+    }
+
     /// DO NOT USE THIS OUTSIDE TESTING
     pub fn with_insecure_skip_verify_this_is_a_bad_idea(mut self) -> Self {
         self.insecure_skip_verify = true;
@@ -223,16 +271,20 @@ impl OpenPgpValidatorBuilder {
     /// Build the provided OpenPgpValidator.
     pub fn build(self) -> Result<OpenPgpValidator, OpenPgpValidatorError> {
         let keys = {
-            let mut keys = HashMap::new();
+            let mut certs = self.certs;
             for keyring in self.keyrings {
                 for cert in
                     CertParser::from_file(keyring).map_err(OpenPgpValidatorError::Sequoia)?
                 {
-                    let cert = cert.map_err(OpenPgpValidatorError::Sequoia)?;
-                    keys.insert(cert.fingerprint(), cert.clone());
-                    for key in cert.keys() {
-                        keys.insert(key.key().fingerprint(), cert.clone());
-                    }
+                    certs.push(cert.map_err(OpenPgpValidatorError::Sequoia)?);
+                }
+            }
+
+            let mut keys = HashMap::new();
+            for cert in certs {
+                keys.insert(cert.fingerprint(), cert.clone());
+                for key in cert.keys() {
+                    keys.insert(key.key().fingerprint(), cert.clone());
                 }
             }
             keys
@@ -245,6 +297,21 @@ impl OpenPgpValidatorBuilder {
     }
 }
 
+/// Return the primary key fingerprint of every certificate in the keyring
+/// at `path`, in the order they appear in the file.
+///
+/// This is handy when trying to figure out why a signature didn't verify,
+/// by confirming the expected key is actually in the keyring being used.
+pub fn keyring_fingerprints(path: &Path) -> Result<Vec<Fingerprint>, OpenPgpValidatorError> {
+    CertParser::from_file(path)
+        .map_err(OpenPgpValidatorError::Sequoia)?
+        .map(|cert| {
+            cert.map(|cert| cert.fingerprint())
+                .map_err(OpenPgpValidatorError::Sequoia)
+        })
+        .collect()
+}
+
 /// This is a thin wrapper around the [OpenPgpValidator], but with all of the
 /// moving parts possible removed. This is going to be an interface that we
 /// can make a bit more generic than the concrete [OpenPgpValidator] interface,
@@ -258,4 +325,35 @@ pub(crate) fn verify(
     verifier.validate(input.as_bytes())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_KEYRING: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/keyrings/test-keyring.asc"
+    );
+
+    #[test]
+    fn test_keyring_fingerprints() {
+        let fingerprints = keyring_fingerprints(Path::new(TEST_KEYRING)).unwrap();
+        assert_eq!(
+            vec![
+                "E2BD46D6121F520D68FC255796EC30925346D161"
+                    .parse::<Fingerprint>()
+                    .unwrap(),
+                "A990C9EBEB761B778A17422AA63D59D6CACBD6DE"
+                    .parse::<Fingerprint>()
+                    .unwrap(),
+            ],
+            fingerprints
+        );
+    }
+
+    #[test]
+    fn test_keyring_fingerprints_missing() {
+        assert!(keyring_fingerprints(Path::new("/nonexistent/keyring.gpg")).is_err());
+    }
+}
+
 // vim: foldmethod=marker
@@ -18,10 +18,10 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
-use super::SourceName;
+use super::{PackageType, SourceName};
 use crate::{
     architecture::Architecture,
-    control::{Number, Priority},
+    control::{Maintainer, Number, Priority},
     dependency::Dependency,
     version::Version,
 };
@@ -38,6 +38,11 @@ pub struct BinaryControl {
     /// Binary package name
     pub package: String,
 
+    /// Type of binary package. If this isn't set, the package is a
+    /// [PackageType::Deb]; see [BinaryControl::package_type].
+    #[cfg_attr(feature = "serde", serde(rename = "Package-Type"))]
+    pub package_type: Option<PackageType>,
+
     /// The value of this field determines the package name, and is used to
     /// generate file names by most installation tools.
     pub source: Option<SourceName>,
@@ -69,7 +74,7 @@ pub struct BinaryControl {
     pub installed_size: Option<Number<usize>>,
 
     /// Name and email of the package's maintainer.
-    pub maintainer: String,
+    pub maintainer: Maintainer,
 
     /// Description of this binary package's purpose.
     pub description: String,
@@ -110,33 +115,253 @@ pub struct BinaryControl {
     /// package.
     pub breaks: Option<Dependency>,
 
+    /// Packages whose files this binary package may overwrite, or which
+    /// this package replaces entirely.
+    pub replaces: Option<Dependency>,
+
     /// Package makes another package better.
     pub enhances: Option<Dependency>,
 
     /// Packages which must be installed before this binary begins to
     /// unpack.
+    #[cfg_attr(feature = "serde", serde(rename = "Pre-Depends"))]
     pub pre_depends: Option<Dependency>,
 }
 
-// #[cfg(test)]
-// mod tests {
-//     #[cfg(feature = "serde")]
-//     use super::*;
-//
-//     #[cfg(feature = "serde")]
-//     mod serde {
-//         use super::*;
-//         use crate::{architecture, control::de};
-//
-//         macro_rules! test_package {
-//             ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
-//                 #[test]
-//                 fn $name() {
-//                     let $parsed = de::from_str::<Package>($data).unwrap();
-//                     $block
-//                 }
-//             };
-//         }
-// }
+impl BinaryControl {
+    /// Return the [PackageType] of this binary package. This is the
+    /// `Package-Type` if set, otherwise a package in the
+    /// `debian-installer` section is a [PackageType::Udeb], and anything
+    /// else is a [PackageType::Deb].
+    pub fn package_type(&self) -> PackageType {
+        if let Some(package_type) = &self.package_type {
+            return package_type.clone();
+        }
+        match &self.section {
+            Some(section) if section.rsplit('/').next() == Some("debian-installer") => {
+                PackageType::Udeb
+            }
+            _ => PackageType::Deb,
+        }
+    }
+
+    /// Return `true` if this is a [PackageType::Udeb], meant for use by the
+    /// Debian Installer.
+    pub fn is_udeb(&self) -> bool {
+        self.package_type() == PackageType::Udeb
+    }
+
+    /// Return the conventional file name of this binary package, in the
+    /// form `<package>_<version>_<architecture>.<type>`, such as
+    /// `hello_2.10-3_amd64.deb`, the same as `dpkg-deb --build` names it.
+    /// The extension is the [BinaryControl::package_type], so a
+    /// [PackageType::Udeb] ends in `.udeb`.
+    ///
+    /// The epoch is never part of the file name (`1:2.10-3` becomes
+    /// `hello_2.10-3_amd64.deb`), which is also how the file is named in
+    /// the archive pool, so there's never a `:` to escape. Note that `apt`
+    /// keeps downloaded files in its cache under a different name, which
+    /// does include the epoch (as `%3a`).
+    ///
+    /// If the `Architecture` field is missing, the `_<architecture>` part
+    /// is left out.
+    pub fn deb_filename(&self) -> String {
+        let version = match self.version.debian_revision() {
+            Some(revision) => format!("{}-{}", self.version.upstream_version(), revision),
+            None => self.version.upstream_version().to_owned(),
+        };
+        let extension = self.package_type();
+        match &self.architecture {
+            Some(arch) => format!("{}_{}_{}.{}", self.package, version, arch, extension),
+            None => format!("{}_{}.{}", self.package, version, extension),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::control::ser::FieldOrder for BinaryControl {
+    const FIELD_ORDER: &'static [&'static str] = &[
+        "Package",
+        "Package-Type",
+        "Source",
+        "Version",
+        "Architecture",
+        "Essential",
+        "Maintainer",
+        "Installed-Size",
+        "Pre-Depends",
+        "Depends",
+        "Recommends",
+        "Suggests",
+        "Breaks",
+        "Conflicts",
+        "Replaces",
+        "Enhances",
+        "Provides",
+        "Built-Using",
+        "Section",
+        "Priority",
+        "Homepage",
+        "Description",
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use crate::{architecture, control::de};
+
+        macro_rules! test_binary_control {
+            ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
+                #[test]
+                fn $name() {
+                    let $parsed = de::from_str::<BinaryControl>($data).unwrap();
+                    $block
+                }
+            };
+        }
+
+        test_binary_control!(
+            hello_deb_control,
+            "\
+Package: hello
+Version: 2.10-2
+Architecture: amd64
+Maintainer: Santiago Vila <sanvila@debian.org>
+Installed-Size: 277
+Depends: libc6 (>= 2.14)
+Conflicts: hello-traditional
+Breaks: hello-debhelper (<< 2.9)
+Replaces: hello-debhelper (<< 2.9), hello-traditional
+Section: devel
+Priority: optional
+Homepage: http://www.gnu.org/software/hello/
+Description: example package based on GNU hello
+ The GNU hello program produces a familiar, friendly greeting.  It
+ allows non-programmers to use a classic computer science tool which
+ would otherwise be unavailable to them.
+",
+            |control| {
+                assert_eq!("hello", control.package);
+                assert_eq!(Some(architecture::AMD64), control.architecture);
+
+                let depends = control.depends.as_ref().unwrap();
+                assert_eq!(1, depends.relations.len());
+                assert_eq!("libc6", depends.relations[0].packages[0].name);
+
+                let breaks = control.breaks.as_ref().unwrap();
+                assert_eq!("hello-debhelper (<< 2.9)", breaks.to_string());
+
+                let replaces = control.replaces.as_ref().unwrap();
+                assert_eq!(2, replaces.relations.len());
+                assert_eq!(
+                    vec!["hello-debhelper", "hello-traditional"],
+                    replaces
+                        .relations
+                        .iter()
+                        .flat_map(|relation| relation.packages.iter())
+                        .map(|package| package.name.as_str())
+                        .collect::<Vec<_>>()
+                );
+
+                assert!(control.recommends.is_none());
+                assert!(control.pre_depends.is_none());
+
+                assert_eq!(None, control.package_type);
+                assert_eq!(PackageType::Deb, control.package_type());
+                assert!(!control.is_udeb());
+            }
+        );
+
+        test_binary_control!(
+            udeb_control,
+            "\
+Package: hello-udeb
+Package-Type: udeb
+Version: 2.10-3
+Architecture: amd64
+Maintainer: Santiago Vila <sanvila@debian.org>
+Installed-Size: 120
+Depends: libc6-udeb (>= 2.36)
+Section: debian-installer
+Priority: optional
+Description: example package based on GNU hello
+",
+            |control| {
+                assert_eq!(Some(PackageType::Udeb), control.package_type);
+                assert!(control.is_udeb());
+            }
+        );
+
+        test_binary_control!(
+            deb_filename,
+            "\
+Package: hello
+Version: 2.10-3
+Architecture: amd64
+Maintainer: Santiago Vila <sanvila@debian.org>
+Installed-Size: 280
+Depends: libc6 (>= 2.34)
+Section: devel
+Priority: optional
+Homepage: https://www.gnu.org/software/hello/
+Description: example package based on GNU hello
+",
+            |control| {
+                assert_eq!("hello_2.10-3_amd64.deb", control.deb_filename());
+            }
+        );
+
+        test_binary_control!(
+            deb_filename_epoch,
+            "\
+Package: hello
+Version: 1:2.10-3
+Architecture: all
+Maintainer: Santiago Vila <sanvila@debian.org>
+Description: example package based on GNU hello
+",
+            |control| {
+                assert_eq!("hello_2.10-3_all.deb", control.deb_filename());
+            }
+        );
+
+        test_binary_control!(
+            deb_filename_native_udeb,
+            "\
+Package: hello-udeb
+Package-Type: udeb
+Version: 2.10
+Architecture: amd64
+Maintainer: Santiago Vila <sanvila@debian.org>
+Description: example package based on GNU hello
+",
+            |control| {
+                assert_eq!("hello-udeb_2.10_amd64.udeb", control.deb_filename());
+            }
+        );
+
+        test_binary_control!(
+            udeb_control_section,
+            "\
+Package: hello-udeb
+Version: 2.10-3
+Architecture: amd64
+Maintainer: Santiago Vila <sanvila@debian.org>
+Section: debian-installer
+Description: example package based on GNU hello
+",
+            |control| {
+                assert_eq!(None, control.package_type);
+                assert!(control.is_udeb());
+            }
+        );
+    }
+}
 
 // vim: foldmethod=marker
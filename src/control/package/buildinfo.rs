@@ -30,6 +30,7 @@ use crate::{
     dependency::Dependency,
     version::Version,
 };
+use std::{collections::BTreeMap, ops::Deref};
 
 // TODO
 //   - format enum
@@ -175,28 +176,396 @@ pub struct Buildinfo {
     /// build process, with each environment variable followed by an equal sign
     /// (`=`) and the variable's quoted value, using double quotes (`"`),
     /// and backslashes escaped (`\\`).
-    pub environment: Vec<String>,
+    pub environment: BuildEnvironment,
 }
 
-// #[cfg(test)]
-// mod tests {
-//     #[cfg(feature = "serde")]
-//     use super::*;
-//
-//     #[cfg(feature = "serde")]
-//     mod serde {
-//         use super::*;
-//         use crate::{architecture, control::de};
-//
-//         macro_rules! test_package {
-//             ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
-//                 #[test]
-//                 fn $name() {
-//                     let $parsed = de::from_str::<Package>($data).unwrap();
-//                     $block
-//                 }
-//             };
-//         }
-// }
+/// Environment variables from the `Environment` field of a [Buildinfo],
+/// keyed by the variable name, with the quoting and escaping removed
+/// from each value.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct BuildEnvironment(pub BTreeMap<String, String>);
+
+impl Deref for BuildEnvironment {
+    type Target = BTreeMap<String, String>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Error conditions which may be encountered when working with a
+/// [BuildEnvironment].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BuildEnvironmentParseError {
+    /// A line didn't have an equal sign (`=`) separating the name and the
+    /// value.
+    MissingEquals,
+
+    /// A value wasn't wrapped in double quotes (`"`), or contained an
+    /// unescaped quote or a trailing backslash.
+    BadQuoting,
+}
+crate::errors::error_enum!(BuildEnvironmentParseError);
+
+impl BuildEnvironment {
+    fn parse_line(line: &str) -> Result<(String, String), BuildEnvironmentParseError> {
+        let (name, value) = line
+            .split_once('=')
+            .ok_or(BuildEnvironmentParseError::MissingEquals)?;
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .ok_or(BuildEnvironmentParseError::BadQuoting)?;
+
+        let mut unescaped = String::with_capacity(value.len());
+        let mut chars = value.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => unescaped.push(chars.next().ok_or(BuildEnvironmentParseError::BadQuoting)?),
+                '"' => return Err(BuildEnvironmentParseError::BadQuoting),
+                _ => unescaped.push(ch),
+            }
+        }
+        Ok((name.to_owned(), unescaped))
+    }
+
+    /// Write out each variable as a `NAME="value"` line, in the same
+    /// format as the `Environment` field.
+    pub fn to_lines(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|(name, value)| {
+                let value = value.replace('\\', r"\\").replace('"', r#"\""#);
+                format!(r#"{name}="{value}""#)
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<&[String]> for BuildEnvironment {
+    type Error = BuildEnvironmentParseError;
+
+    fn try_from(lines: &[String]) -> Result<Self, Self::Error> {
+        Ok(Self(
+            lines
+                .iter()
+                .map(|line| Self::parse_line(line))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::BuildEnvironment;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    impl Serialize for BuildEnvironment {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.to_lines().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for BuildEnvironment {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let lines = Vec::<String>::deserialize(d)?;
+            BuildEnvironment::try_from(lines.as_slice())
+                .map_err(|e| D::Error::custom(format!("{e:?}")))
+        }
+    }
+}
+
+/// Difference between the `Installed-Build-Depends` of two [Buildinfo]
+/// files, as returned by [Buildinfo::diff_build_depends].
+///
+/// Packages are identified by name, along with the architecture
+/// qualifier if one was given (such as `libc6:i386`). Each list is sorted
+/// by that name.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct BuildDepsDiff {
+    /// Packages which were only installed in the other build.
+    pub added: Vec<(String, Version)>,
+
+    /// Packages which were only installed in this build.
+    pub removed: Vec<(String, Version)>,
+
+    /// Packages which were installed in both builds, but at different
+    /// versions. This is the name, the version in this build, and the
+    /// version in the other build.
+    pub changed: Vec<(String, Version, Version)>,
+}
+
+impl BuildDepsDiff {
+    /// Return `true` if both builds had exactly the same packages
+    /// installed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl Buildinfo {
+    /// Return the `SOURCE_DATE_EPOCH` the package was built with, as
+    /// seconds since the Unix epoch, if it was set in the `Environment`.
+    pub fn source_date_epoch(&self) -> Option<u64> {
+        self.environment.get("SOURCE_DATE_EPOCH")?.parse().ok()
+    }
+
+    /// Return the parsed `Build-Date` of this build, if one was set.
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+    pub fn build_date(&self) -> Option<&::chrono::DateTime<::chrono::FixedOffset>> {
+        self.build_date.as_ref().map(|when| when.to_datetime())
+    }
+
+    /// Return every package in `Installed-Build-Depends` along with its
+    /// installed version. Entries which don't have a version constraint
+    /// are skipped.
+    fn installed_build_depends_versions(&self) -> BTreeMap<String, &Version> {
+        self.installed_build_depends
+            .relations
+            .iter()
+            .flat_map(|relation| relation.packages.iter())
+            .filter_map(|package| {
+                let version = &package.version_constraint.as_ref()?.version;
+                let name = match &package.arch {
+                    Some(arch) => format!("{}:{}", package.name, arch),
+                    None => package.name.clone(),
+                };
+                Some((name, version))
+            })
+            .collect()
+    }
+
+    /// Compare the `Installed-Build-Depends` of this [Buildinfo] against
+    /// `other`, returning the packages which were added, removed, or
+    /// installed at a different version in `other`.
+    pub fn diff_build_depends(&self, other: &Buildinfo) -> BuildDepsDiff {
+        let ours = self.installed_build_depends_versions();
+        let theirs = other.installed_build_depends_versions();
+
+        let mut diff = BuildDepsDiff::default();
+        for (name, version) in &ours {
+            match theirs.get(name) {
+                None => diff.removed.push((name.clone(), (*version).clone())),
+                Some(other_version) if other_version != version => {
+                    diff.changed
+                        .push((name.clone(), (*version).clone(), (*other_version).clone()))
+                }
+                Some(_) => {}
+            }
+        }
+        for (name, version) in &theirs {
+            if !ours.contains_key(name) {
+                diff.added.push((name.clone(), (*version).clone()));
+            }
+        }
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! check_environment {
+        ($name:ident, $line:expr, $key:expr, $value:expr) => {
+            #[test]
+            fn $name() {
+                let env = BuildEnvironment::try_from([$line.to_owned()].as_slice()).unwrap();
+                assert_eq!($value, env[$key]);
+                assert_eq!(vec![$line.to_owned()], env.to_lines());
+            }
+        };
+    }
+
+    check_environment!(env_simple, r#"LANG="C.UTF-8""#, "LANG", "C.UTF-8");
+    check_environment!(env_empty, r#"EMPTY="""#, "EMPTY", "");
+    check_environment!(env_equals, r#"OPTS="parallel=4""#, "OPTS", "parallel=4");
+    check_environment!(env_quote, r#"FOO="a \"b\" c""#, "FOO", r#"a "b" c"#);
+    check_environment!(env_backslash, r#"FOO="a\\b""#, "FOO", r"a\b");
+
+    macro_rules! check_environment_fails {
+        ($name:ident, $line:expr) => {
+            #[test]
+            fn $name() {
+                assert!(BuildEnvironment::try_from([$line.to_owned()].as_slice()).is_err());
+            }
+        };
+    }
+
+    check_environment_fails!(env_fail_no_equals, "LANG");
+    check_environment_fails!(env_fail_unquoted, "LANG=C.UTF-8");
+    check_environment_fails!(env_fail_inner_quote, r#"LANG="C"UTF-8""#);
+    check_environment_fails!(env_fail_trailing_backslash, r#"LANG="C\""#);
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use crate::control::{de, ser};
+
+        macro_rules! test_buildinfo {
+            ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
+                #[test]
+                fn $name() {
+                    let $parsed = de::from_str::<Buildinfo>($data).unwrap();
+                    $block
+                }
+            };
+        }
+
+        const HELLO_BUILDINFO: &str = "\
+Format: 1.0
+Source: hello
+Binary: hello hello-dbgsym
+Architecture: amd64
+Version: 2.10-3
+Checksums-Sha256:
+ 052cb5fdfa86bb3485d6194d9ae2fd1cabbccbdd9c7da3258aed1674b288bbf9 53324 hello_2.10-3_amd64.deb
+Build-Origin: Debian
+Build-Architecture: amd64
+Build-Date: Mon, 26 Dec 2022 15:30:21 +0000
+Build-Path: /build/reproducible-path/hello-2.10
+Installed-Build-Depends:
+ autoconf (= 2.71-3),
+ debhelper (= 13.11.4),
+ gcc-12 (= 12.2.0-14),
+ libc6 (= 2.36-8),
+ libc6:i386 (= 2.36-8),
+ texinfo (= 6.8-6+b1)
+Environment:
+ DEB_BUILD_OPTIONS=\"parallel=4\"
+ LANG=\"C.UTF-8\"
+ LC_ALL=\"C.UTF-8\"
+ SOURCE_DATE_EPOCH=\"1672068600\"
+";
+
+        test_buildinfo!(hello_parses, HELLO_BUILDINFO, |buildinfo| {
+            assert_eq!("hello", buildinfo.source);
+            assert_eq!(6, buildinfo.installed_build_depends.relations.len());
+            assert_eq!(4, buildinfo.environment.len());
+            assert_eq!("C.UTF-8", buildinfo.environment["LANG"]);
+            assert_eq!("parallel=4", buildinfo.environment["DEB_BUILD_OPTIONS"]);
+            assert_eq!(Some(1672068600), buildinfo.source_date_epoch());
+        });
+
+        test_buildinfo!(
+            no_source_date_epoch,
+            &HELLO_BUILDINFO.replace(" SOURCE_DATE_EPOCH=\"1672068600\"\n", ""),
+            |buildinfo| {
+                assert_eq!(None, buildinfo.source_date_epoch());
+            }
+        );
+
+        #[cfg(feature = "chrono")]
+        test_buildinfo!(build_date, HELLO_BUILDINFO, |buildinfo| {
+            let when = buildinfo.build_date().unwrap();
+            assert_eq!(1672068621, when.timestamp());
+            assert_eq!(0, when.offset().local_minus_utc());
+        });
+
+        #[cfg(feature = "chrono")]
+        test_buildinfo!(
+            build_date_negative_offset,
+            &HELLO_BUILDINFO.replace(
+                "Mon, 26 Dec 2022 15:30:21 +0000",
+                "Wed, 04 Dec 2024 15:18:38 -0500"
+            ),
+            |buildinfo| {
+                let when = buildinfo.build_date().unwrap();
+                assert_eq!(1733343518, when.timestamp());
+                assert_eq!(-5 * 3600, when.offset().local_minus_utc());
+
+                let encoded = ser::to_string(&buildinfo).unwrap();
+                assert!(encoded.contains("\nBuild-Date: Wed, 04 Dec 2024 15:18:38 -0500\n"));
+            }
+        );
+
+        test_buildinfo!(environment_ser, HELLO_BUILDINFO, |buildinfo| {
+            let encoded = ser::to_string(&buildinfo).unwrap();
+            assert!(encoded.contains(
+                "
+Environment:
+ DEB_BUILD_OPTIONS=\"parallel=4\"
+ LANG=\"C.UTF-8\"
+ LC_ALL=\"C.UTF-8\"
+ SOURCE_DATE_EPOCH=\"1672068600\"
+"
+            ));
+        });
+
+        #[test]
+        fn bad_environment() {
+            assert!(
+                de::from_str::<Buildinfo>(
+                    &HELLO_BUILDINFO.replace("LANG=\"C.UTF-8\"", "LANG=C.UTF-8")
+                )
+                .is_err()
+            );
+        }
+
+        test_buildinfo!(diff_same, HELLO_BUILDINFO, |buildinfo| {
+            assert!(buildinfo.diff_build_depends(&buildinfo).is_empty());
+        });
+
+        test_buildinfo!(diff_one_version, HELLO_BUILDINFO, |buildinfo| {
+            let other: Buildinfo = de::from_str(
+                &HELLO_BUILDINFO.replace("gcc-12 (= 12.2.0-14)", "gcc-12 (= 12.3.0-1)"),
+            )
+            .unwrap();
+
+            let diff = buildinfo.diff_build_depends(&other);
+            assert!(diff.added.is_empty());
+            assert!(diff.removed.is_empty());
+            assert_eq!(
+                vec![(
+                    "gcc-12".to_owned(),
+                    "12.2.0-14".parse().unwrap(),
+                    "12.3.0-1".parse().unwrap()
+                )],
+                diff.changed
+            );
+        });
+
+        test_buildinfo!(diff_added_removed, HELLO_BUILDINFO, |buildinfo| {
+            let other: Buildinfo = de::from_str(
+                &HELLO_BUILDINFO
+                    .replace(" autoconf (= 2.71-3),\n", "")
+                    .replace(
+                        "libc6:i386 (= 2.36-8)",
+                        "libc6:i386 (= 2.36-8),\n zlib1g (= 1:1.2.13)",
+                    ),
+            )
+            .unwrap();
+
+            let diff = buildinfo.diff_build_depends(&other);
+            assert_eq!(
+                vec![("zlib1g".to_owned(), "1:1.2.13".parse().unwrap())],
+                diff.added
+            );
+            assert_eq!(
+                vec![("autoconf".to_owned(), "2.71-3".parse().unwrap())],
+                diff.removed
+            );
+            assert!(diff.changed.is_empty());
+
+            let diff = other.diff_build_depends(&buildinfo);
+            assert_eq!("autoconf", diff.added[0].0);
+            assert_eq!("zlib1g", diff.removed[0].0);
+        });
+
+        test_buildinfo!(diff_arch_qualified, HELLO_BUILDINFO, |buildinfo| {
+            let other: Buildinfo = de::from_str(
+                &HELLO_BUILDINFO.replace("libc6:i386 (= 2.36-8)", "libc6:i386 (= 2.36-9)"),
+            )
+            .unwrap();
+
+            let diff = buildinfo.diff_build_depends(&other);
+            assert_eq!(1, diff.changed.len());
+            assert_eq!("libc6:i386", diff.changed[0].0);
+        });
+    }
+}
 
 // vim: foldmethod=marker
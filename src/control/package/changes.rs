@@ -18,12 +18,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
-use super::{File, SourceName};
+use super::{File, Files, SourceName};
 use crate::{
+    architecture,
     build_profile::BuildProfile,
     control::{
-        Architectures, DateTime2822, Delimited, FileDigestSha1, FileDigestSha256,
-        PriorityParseError, SpaceDelimitedStrings,
+        Architectures, DateTime2822, Delimited, FileDigestSha1, FileDigestSha256, Maintainer,
+        PriorityParseError, SpaceDelimitedStrings, archive::pool_path,
     },
     version::Version,
 };
@@ -36,6 +37,35 @@ pub type Closes = SpaceDelimitedStrings;
 #[cfg(feature = "serde")]
 use ::serde::{Deserialize, Serialize};
 
+/// Options to control how lenient [Changes::from_str_with_options] is when
+/// parsing a [Changes] which doesn't quite follow policy.
+///
+/// The [Default] options are strict, and behave the same as decoding a
+/// [Changes] directly.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChangesParseOptions {
+    /// `Urgency` to use if the field is missing. Some automated uploads
+    /// leave it out, even though it's required. If `None`, a missing
+    /// `Urgency` field is an error.
+    pub default_urgency: Option<String>,
+}
+
+/// What an upload described by a [Changes] contains, as returned by
+/// [Changes::upload_kind].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UploadKind {
+    /// Only the source package is being uploaded, and the archive will
+    /// build all the binaries.
+    SourceOnly,
+
+    /// Only binary packages are being uploaded, such as from a buildd or
+    /// for a binNMU.
+    BinaryOnly,
+
+    /// Both the source package and some of its binaries are being uploaded.
+    Mixed,
+}
+
 /// Error conditions which may be encountered when working with a [Changes]
 /// file.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -64,6 +94,15 @@ pub enum ChangesParseError {
 
     /// The "`Priority`" field was an unknown or unsupported value.
     InvalidPriority(PriorityParseError),
+
+    /// The version given in the "`Source`" field is newer than the
+    /// "`Version`" of the upload. Binaries can't have been built from a
+    /// source that came after them.
+    SourceVersionNewer,
+
+    /// The upload contains the source package, but the version given in
+    /// the "`Source`" field doesn't match the "`Version`" of the upload.
+    SourceVersionMismatch,
 }
 crate::errors::error_enum!(ChangesParseError);
 
@@ -144,12 +183,12 @@ pub struct Changes {
     /// typically the person who created the package, as opposed to the
     /// author of the software that was packaged.
     #[cfg_attr(feature = "serde", serde(rename = "Maintainer"))]
-    pub maintainer: String,
+    pub maintainer: Maintainer,
 
     /// Should be in the format "Joe Bloggs <jbloggs@example.org>", and is
     /// typically the person who prepared the package changes for this release.
     #[cfg_attr(feature = "serde", serde(rename = "Changed-By"))]
-    pub changed_by: Option<String>,
+    pub changed_by: Option<Maintainer>,
 
     /// This multiline field contains a list of binary package names followed
     /// by a space, a dash ('-') and their possibly truncated short descriptions.
@@ -196,7 +235,7 @@ pub struct Changes {
     /// to be sufficient for secure verification, but this field cannot be
     /// omitted as it provides metadata not available anywhere else.
     #[cfg_attr(feature = "serde", serde(rename = "Files"))]
-    pub files: Vec<File>,
+    pub files: Files<File>,
 
     /// Each line consists of space-separated entries describing the file:
     /// the checksum, the file size, and the file name.
@@ -220,22 +259,353 @@ pub struct Changes {
     pub checksum_sha256: Option<Vec<FileDigestSha256>>,
 }
 
+/// Parse a list of bug numbers following a `Closes:` or `LP:` marker,
+/// such as `#123, #456` or `Bug#123`, pushing each onto `bugs`.
+fn parse_bug_list(mut rest: &str, allow_bug_prefix: bool, bugs: &mut Vec<u32>) {
+    loop {
+        let mut item = rest.trim_start();
+        if allow_bug_prefix && let Some(after) = item.strip_prefix("bug") {
+            item = after;
+        }
+        item = item.strip_prefix('#').unwrap_or(item);
+        item = item.strip_prefix(' ').unwrap_or(item);
+
+        let digits = item.len() - item.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let Ok(bug) = item[..digits].parse() else {
+            return;
+        };
+        if !bugs.contains(&bug) {
+            bugs.push(bug);
+        }
+
+        match item[digits..].strip_prefix(',') {
+            Some(next) => rest = next,
+            None => return,
+        }
+    }
+}
+
+/// Marker in changelog text which is followed by a list of closed bugs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BugMarker {
+    /// `Closes: #NNN`, for bugs in the Debian BTS.
+    Closes,
+
+    /// `LP: #NNN`, for bugs in Launchpad.
+    Launchpad,
+}
+
+impl BugMarker {
+    fn marker(self) -> &'static str {
+        match self {
+            Self::Closes => "closes",
+            Self::Launchpad => "lp",
+        }
+    }
+}
+
+/// Find all bugs closed by the provided [BugMarker] in changelog text, in
+/// the order they're first mentioned.
+fn bugs_closed_in(text: &str, marker: BugMarker) -> Vec<u32> {
+    let text = text.to_ascii_lowercase();
+    let mut bugs = vec![];
+
+    for (idx, _) in text.match_indices(':') {
+        let before = &text[..idx];
+        let Some(before) = before.strip_suffix(marker.marker()) else {
+            continue;
+        };
+
+        // Don't match the tail of some other word, like `help:`.
+        if before
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+        {
+            continue;
+        }
+
+        parse_bug_list(&text[idx + 1..], marker == BugMarker::Closes, &mut bugs);
+    }
+
+    bugs
+}
+
+impl Changes {
+    /// Return the Debian bug numbers closed in the `Changes` text, by
+    /// scanning it for `Closes: #NNN` (or lists like `Closes: #NNN, #MMM`)
+    /// markers, in the order they're first mentioned.
+    ///
+    /// This is independent of the structured [Changes::closes] field,
+    /// which should match the bugs found here. Launchpad bugs are returned
+    /// by [Changes::launchpad_bugs_from_body].
+    pub fn closes_from_body(&self) -> Vec<u32> {
+        bugs_closed_in(&self.changes, BugMarker::Closes)
+    }
+
+    /// Return the Launchpad bug numbers closed in the `Changes` text, by
+    /// scanning it for `LP: #NNN` (or lists like `LP: #NNN, #MMM`)
+    /// markers, in the order they're first mentioned.
+    pub fn launchpad_bugs_from_body(&self) -> Vec<u32> {
+        bugs_closed_in(&self.changes, BugMarker::Launchpad)
+    }
+
+    /// Return the names of the binary packages listed in `Binary`. If
+    /// `include_dbgsym` is `false`, debug packages (names ending in
+    /// `-dbgsym` or `-dbg`) are left out.
+    pub fn binary_packages(&self, include_dbgsym: bool) -> Vec<&str> {
+        let Some(binary) = &self.binary else {
+            return vec![];
+        };
+        binary
+            .iter()
+            .map(|name| name.as_str())
+            .filter(|name| !name.is_empty())
+            .filter(|name| include_dbgsym || !(name.ends_with("-dbgsym") || name.ends_with("-dbg")))
+            .collect()
+    }
+
+    /// Return the names of the binary packages this upload actually ships
+    /// files for, taken from the `.deb` and `.udeb` entries in `Files`
+    /// (named like `hello_2.10-3_amd64.deb`), in the order they're first
+    /// listed.
+    ///
+    /// `Files` is the authoritative list of what's been uploaded, while
+    /// `Binary` is what was intended. Any `Binary` entry without a file is
+    /// returned by [Changes::unshipped_binaries].
+    pub fn shipped_packages(&self) -> Vec<String> {
+        let mut packages: Vec<String> = vec![];
+        for file in self.files.iter() {
+            if !(file.path.ends_with(".deb") || file.path.ends_with(".udeb")) {
+                continue;
+            }
+            let Some((name, _)) = file.path.split_once('_') else {
+                continue;
+            };
+            if !packages.iter().any(|package| package == name) {
+                packages.push(name.to_owned());
+            }
+        }
+        packages
+    }
+
+    /// Return the names of any binary packages listed in `Binary` which
+    /// this upload doesn't ship a `.deb` or `.udeb` for, as found by
+    /// [Changes::shipped_packages].
+    pub fn unshipped_binaries(&self) -> Vec<&str> {
+        let shipped = self.shipped_packages();
+        self.binary_packages(true)
+            .into_iter()
+            .filter(|name| !shipped.iter().any(|package| package == name))
+            .collect()
+    }
+
+    /// Check that the version in the `Source` field (if present) is
+    /// consistent with the `Version` of this upload.
+    ///
+    /// A binary-only upload, such as a binNMU, may be of a newer `Version`
+    /// than the source it was built from (`Source: hello (2.10-3)` with
+    /// `Version: 2.10-3+b1`), but never an older one. If the upload
+    /// includes the source package itself, the versions must be the same.
+    pub fn validate_versions(&self) -> Result<(), ChangesParseError> {
+        let Some(source_version) = &self.source.version else {
+            return Ok(());
+        };
+
+        if source_version > &self.version {
+            return Err(ChangesParseError::SourceVersionNewer);
+        }
+
+        if self.architecture.contains(&architecture::SOURCE) && source_version != &self.version {
+            return Err(ChangesParseError::SourceVersionMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Return what kind of upload this is, based on the `Architecture`
+    /// field:
+    ///
+    /// - If it lists `source` and nothing else, this is a
+    ///   [UploadKind::SourceOnly] upload.
+    /// - If it lists `source` and any binary architecture (including
+    ///   `all`), this is a [UploadKind::Mixed] upload.
+    /// - If it doesn't list `source`, this is a [UploadKind::BinaryOnly]
+    ///   upload.
+    ///
+    /// The `Binary` field isn't used to decide, since older versions of
+    /// `dpkg-genchanges` listed every binary the source builds there, even
+    /// for a source-only upload (current versions omit it). It's only
+    /// consulted if `Architecture` is empty, in which case a `Binary`
+    /// field means a [UploadKind::BinaryOnly] upload, and no `Binary`
+    /// field means a [UploadKind::SourceOnly] one.
+    pub fn upload_kind(&self) -> UploadKind {
+        let has_source = self.architecture.contains(&architecture::SOURCE);
+        let has_binary = self
+            .architecture
+            .iter()
+            .any(|arch| *arch != architecture::SOURCE);
+
+        match (has_source, has_binary) {
+            (true, true) => UploadKind::Mixed,
+            (true, false) => UploadKind::SourceOnly,
+            (false, true) => UploadKind::BinaryOnly,
+            (false, false) => {
+                if self.binary_packages(true).is_empty() {
+                    UploadKind::SourceOnly
+                } else {
+                    UploadKind::BinaryOnly
+                }
+            }
+        }
+    }
+
+    /// Return the URL that `file` from this upload will be found at once
+    /// it's in the archive at `base_url`, following the
+    /// `pool/<area>/<prefix>/<source>/<file>` layout. The archive area is
+    /// taken from the file's section (`contrib/devel` is in `contrib`),
+    /// defaulting to `main`.
+    pub fn download_url_for_file(&self, base_url: &str, file: &File) -> String {
+        let area = match file.section.split_once('/') {
+            Some((area, _)) => area,
+            None => "main",
+        };
+        format!(
+            "{}/{}",
+            base_url.trim_end_matches('/'),
+            pool_path(area, &self.source.name, &file.path)
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl crate::control::ser::FieldOrder for Changes {
+    const FIELD_ORDER: &'static [&'static str] = &[
+        "Format",
+        "Date",
+        "Source",
+        "Binary",
+        "Binary-Only",
+        "Built-For-Profiles",
+        "Architecture",
+        "Version",
+        "Distribution",
+        "Urgency",
+        "Maintainer",
+        "Changed-By",
+        "Description",
+        "Closes",
+        "Changes",
+        "Checksums-Sha1",
+        "Checksums-Sha256",
+        "Files",
+    ];
+}
+
 #[cfg(feature = "serde")]
 mod serde {
+    use super::{Changes, ChangesParseOptions};
+    use crate::control::{RawParagraph, de};
+
+    impl Changes {
+        /// Decode a [Changes] from the provided paragraph, the same way
+        /// [de::from_str] would, but using the provided
+        /// [ChangesParseOptions] to fill in for some missing fields.
+        pub fn from_str_with_options(
+            input: &str,
+            options: &ChangesParseOptions,
+        ) -> Result<Changes, de::Error> {
+            let paragraph =
+                RawParagraph::parse(input.trim_start()).map_err(de::Error::ParseError)?;
+
+            let default_urgency = match &options.default_urgency {
+                Some(urgency) if paragraph.field("Urgency").next().is_none() => {
+                    Some(("Urgency", urgency.as_str()))
+                }
+                _ => None,
+            };
+
+            de::from_fields(
+                paragraph
+                    .fields
+                    .iter()
+                    .map(|field| (field.key.as_ref(), field.value.as_ref()))
+                    .chain(default_urgency),
+            )
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use crate::{
             architecture,
             control::{
                 self,
-                package::{Changes, File},
+                package::{Changes, ChangesParseError, ChangesParseOptions, File, UploadKind},
             },
         };
         use std::io::{BufReader, Cursor};
 
-        #[test]
-        fn test_changes() {
-            let mut reader = BufReader::new(Cursor::new("\
+        use super::super::{BugMarker, bugs_closed_in};
+
+        macro_rules! check_bugs_closed_in {
+            ($name:ident, $text:expr, $marker:expr, $bugs:expr) => {
+                #[test]
+                fn $name() {
+                    let expected: &[u32] = &$bugs;
+                    assert_eq!(expected, bugs_closed_in($text, $marker));
+                }
+            };
+        }
+
+        check_bugs_closed_in!(closes, "* Fix it. Closes: #123.", BugMarker::Closes, [123]);
+        check_bugs_closed_in!(
+            closes_no_hash,
+            "* Fix it (closes: 123)",
+            BugMarker::Closes,
+            [123]
+        );
+        check_bugs_closed_in!(
+            closes_bug,
+            "* Fix it. Closes: Bug#123",
+            BugMarker::Closes,
+            [123]
+        );
+        check_bugs_closed_in!(
+            closes_list,
+            "* Fix. Closes: #1, #2,#3",
+            BugMarker::Closes,
+            [1, 2, 3]
+        );
+        check_bugs_closed_in!(
+            closes_dup,
+            "* Closes: #1\n* Closes: #1, #2",
+            BugMarker::Closes,
+            [1, 2]
+        );
+        check_bugs_closed_in!(
+            lp,
+            "* Fix it (LP: #1234, #5678)",
+            BugMarker::Launchpad,
+            [1234, 5678]
+        );
+        check_bugs_closed_in!(
+            mixed_closes,
+            "* Closes: #1 (LP: #2)",
+            BugMarker::Closes,
+            [1]
+        );
+        check_bugs_closed_in!(mixed_lp, "* Closes: #1 (LP: #2)", BugMarker::Launchpad, [2]);
+        check_bugs_closed_in!(
+            not_a_marker,
+            "* Add help: #1 and encloses: #2",
+            BugMarker::Closes,
+            []
+        );
+        check_bugs_closed_in!(not_a_marker_lp, "* Add help: #1", BugMarker::Launchpad, []);
+        check_bugs_closed_in!(no_number, "* Closes: nothing", BugMarker::Closes, []);
+
+        const HELLO_CHANGES: &str = "\
 Format: 1.8
 Date: Mon, 26 Dec 2022 16:30:00 +0100
 Source: hello
@@ -282,7 +652,205 @@ Files:
  5b2bcd51a3ad0d0e611aafd9276b938e 36084 debug optional hello-dbgsym_2.10-3_amd64.deb
  57144f2c9158564350da3371b5b9a542 7657 devel optional hello_2.10-3_amd64.buildinfo
  d36abefbc87d8dfb7704238f0aee0e90 53324 devel optional hello_2.10-3_amd64.deb
-"));
+";
+
+        #[test]
+        fn test_binary_packages() {
+            let changes: Changes = control::de::from_str(HELLO_CHANGES).unwrap();
+            assert_eq!(vec!["hello", "hello-dbgsym"], changes.binary_packages(true));
+            assert_eq!(vec!["hello"], changes.binary_packages(false));
+        }
+
+        #[test]
+        fn test_binary_packages_dbg() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES.replace("Binary: hello hello-dbgsym", "Binary: hello-dbg hello"),
+            )
+            .unwrap();
+            assert_eq!(vec!["hello-dbg", "hello"], changes.binary_packages(true));
+            assert_eq!(vec!["hello"], changes.binary_packages(false));
+        }
+
+        #[test]
+        fn test_shipped_packages() {
+            let changes: Changes = control::de::from_str(HELLO_CHANGES).unwrap();
+            assert_eq!(vec!["hello-dbgsym", "hello"], changes.shipped_packages());
+            assert!(changes.unshipped_binaries().is_empty());
+        }
+
+        #[test]
+        fn test_unshipped_binaries() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES
+                    .replace("Binary: hello hello-dbgsym", "Binary: hello hello-dbgsym hello-udeb")
+                    .replace(
+                        " 5b2bcd51a3ad0d0e611aafd9276b938e 36084 debug optional hello-dbgsym_2.10-3_amd64.deb\n",
+                        "",
+                    ),
+            )
+            .unwrap();
+            assert_eq!(vec!["hello"], changes.shipped_packages());
+            assert_eq!(
+                vec!["hello-dbgsym", "hello-udeb"],
+                changes.unshipped_binaries()
+            );
+        }
+
+        #[test]
+        fn test_download_url_for_file() {
+            let changes: Changes = control::de::from_str(HELLO_CHANGES).unwrap();
+            assert_eq!(
+                "http://deb.debian.org/debian/pool/main/h/hello/hello_2.10-3.dsc",
+                changes.download_url_for_file("http://deb.debian.org/debian/", &changes.files[0])
+            );
+            assert_eq!(
+                "http://deb.debian.org/debian/pool/main/h/hello/hello-dbgsym_2.10-3_amd64.deb",
+                changes.download_url_for_file("http://deb.debian.org/debian", &changes.files[2])
+            );
+        }
+
+        #[test]
+        fn test_download_url_for_file_area() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES
+                    .replace("Source: hello", "Source: libhello")
+                    .replace(" devel optional", " non-free/devel optional"),
+            )
+            .unwrap();
+            assert_eq!(
+                "http://deb.debian.org/debian/pool/non-free/libh/libhello/hello_2.10-3.dsc",
+                changes.download_url_for_file("http://deb.debian.org/debian", &changes.files[0])
+            );
+        }
+
+        #[test]
+        fn test_closes_from_body() {
+            let changes: Changes = control::de::from_str(HELLO_CHANGES).unwrap();
+            assert_eq!(vec![871622, 893083], changes.closes_from_body());
+            assert!(changes.launchpad_bugs_from_body().is_empty());
+        }
+
+        #[test]
+        fn test_upload_kind_mixed() {
+            let changes: Changes = control::de::from_str(HELLO_CHANGES).unwrap();
+            assert_eq!(UploadKind::Mixed, changes.upload_kind());
+        }
+
+        #[test]
+        fn test_upload_kind_source_only() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES
+                    .replace("Binary: hello hello-dbgsym\n", "")
+                    .replace("Architecture: source amd64", "Architecture: source"),
+            )
+            .unwrap();
+            assert_eq!(UploadKind::SourceOnly, changes.upload_kind());
+
+            // older dpkg-genchanges still listed the binaries
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES.replace("Architecture: source amd64", "Architecture: source"),
+            )
+            .unwrap();
+            assert_eq!(UploadKind::SourceOnly, changes.upload_kind());
+        }
+
+        #[test]
+        fn test_upload_kind_binary_only() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES.replace("Architecture: source amd64", "Architecture: amd64"),
+            )
+            .unwrap();
+            assert_eq!(UploadKind::BinaryOnly, changes.upload_kind());
+
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES.replace("Architecture: source amd64", "Architecture: all"),
+            )
+            .unwrap();
+            assert_eq!(UploadKind::BinaryOnly, changes.upload_kind());
+        }
+
+        #[test]
+        fn test_upload_kind_mixed_all() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES.replace("Architecture: source amd64", "Architecture: source all"),
+            )
+            .unwrap();
+            assert_eq!(UploadKind::Mixed, changes.upload_kind());
+        }
+
+        #[test]
+        fn test_missing_urgency() {
+            let no_urgency = HELLO_CHANGES.replace("Urgency: medium\n", "");
+
+            assert!(control::de::from_str::<Changes>(&no_urgency).is_err());
+            assert!(
+                Changes::from_str_with_options(&no_urgency, &ChangesParseOptions::default())
+                    .is_err()
+            );
+
+            let lenient = ChangesParseOptions {
+                default_urgency: Some("low".to_owned()),
+            };
+            let changes = Changes::from_str_with_options(&no_urgency, &lenient).unwrap();
+            assert_eq!("low", changes.urgency);
+            assert_eq!("hello", changes.source.name);
+
+            // an Urgency which is there is kept
+            let changes = Changes::from_str_with_options(HELLO_CHANGES, &lenient).unwrap();
+            assert_eq!("medium", changes.urgency);
+            assert_eq!(
+                control::de::from_str::<Changes>(HELLO_CHANGES).unwrap(),
+                changes
+            );
+        }
+
+        #[test]
+        fn test_validate_versions() {
+            let changes: Changes = control::de::from_str(HELLO_CHANGES).unwrap();
+            assert_eq!(Ok(()), changes.validate_versions());
+        }
+
+        #[test]
+        fn test_validate_versions_binnmu() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES
+                    .replace("Source: hello", "Source: hello (2.10-3)")
+                    .replace("Architecture: source amd64", "Architecture: amd64")
+                    .replace("Version: 2.10-3", "Version: 2.10-3+b1"),
+            )
+            .unwrap();
+            assert_eq!(Ok(()), changes.validate_versions());
+        }
+
+        #[test]
+        fn test_validate_versions_source_newer() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES
+                    .replace("Source: hello", "Source: hello (2.10-4)")
+                    .replace("Architecture: source amd64", "Architecture: amd64"),
+            )
+            .unwrap();
+            assert_eq!(
+                Err(ChangesParseError::SourceVersionNewer),
+                changes.validate_versions()
+            );
+        }
+
+        #[test]
+        fn test_validate_versions_source_mismatch() {
+            let changes: Changes = control::de::from_str(
+                &HELLO_CHANGES.replace("Source: hello", "Source: hello (2.10-2)"),
+            )
+            .unwrap();
+            assert_eq!(
+                Err(ChangesParseError::SourceVersionMismatch),
+                changes.validate_versions()
+            );
+        }
+
+        #[test]
+        fn test_changes() {
+            let mut reader = BufReader::new(Cursor::new(HELLO_CHANGES));
 
             let changes: Changes = control::de::from_reader(&mut reader).unwrap();
 
@@ -339,7 +907,7 @@ Files:
                         priority: Some("optional".parse().unwrap()),
                     }
                 ],
-                changes.files,
+                changes.files.0,
             );
 
             assert_eq!(changes.files.len(), changes.checksum_sha1.unwrap().len());
@@ -18,8 +18,9 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
+use super::Testsuites;
 use crate::{
-    control::{Architectures, CommaDelimitedStrings},
+    control::{Architectures, CommaDelimitedStrings, Maintainer, Uploaders},
     dependency::Dependency,
     version::Version,
 };
@@ -68,12 +69,13 @@ pub struct CommonSourceControl {
     /// Should be in the format `Joe Bloggs <jbloggs@foo.com>`, and is
     /// typically the person who created the package, as opposed to the
     /// author of the software that was packaged.
-    pub maintainer: String,
+    pub maintainer: Maintainer,
 
     /// Lists all the names and email addresses of co-maintainers of the
     /// package, in the same format as the Maintainer field. Multiple
-    /// co-maintainers should be separated by a comma.
-    pub uploaders: Option<CommaDelimitedStrings>,
+    /// co-maintainers should be separated by a comma. Commas within a
+    /// quoted name don't separate co-maintainers.
+    pub uploaders: Option<Uploaders>,
 
     /// The format for the source package description is a short brief summary
     /// on the first line (after the Description field). The following lines
@@ -99,7 +101,7 @@ pub struct CommonSourceControl {
     /// autopkgtest value is present, a `debian/tests/control` is expected to
     /// be present, if the file is present but not the value, then
     /// `dpkg-source` will automatically add it, preserving previous values.
-    pub testsuite: Option<CommaDelimitedStrings>,
+    pub testsuite: Option<Testsuites>,
 
     /// This field declares the comma-separated union of all test dependencies
     /// (`Depends` fields in `debian/tests/control` file), with all restrictions
@@ -18,8 +18,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
-use super::{CommonSourceControl, PackageList};
-use crate::control::{FileDigestMd5, FileDigestSha1, FileDigestSha256, PriorityParseError};
+use super::{
+    CommonSourceControl, Files, PackageList, StandardsVersion, StandardsVersionParseError,
+};
+use crate::{
+    control::{FileDigestMd5, FileDigestSha1, FileDigestSha256, PriorityParseError},
+    dependency::{self, Dependency},
+};
 
 #[cfg(feature = "serde")]
 use ::serde::{Deserialize, Serialize};
@@ -48,6 +53,86 @@ pub enum DscParseError {
 }
 crate::errors::error_enum!(DscParseError);
 
+/// Disagreement between the list of files in the `Files` field of a [Dsc],
+/// and one of the `Checksums-*` fields, as found by
+/// [Dsc::verify_file_lists].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileListMismatch {
+    /// A file listed in `Files` is missing from the named field.
+    Missing {
+        /// Name of the field the file is missing from.
+        field: &'static str,
+
+        /// Path of the file which is missing.
+        path: String,
+    },
+
+    /// A file listed in the named field isn't listed in `Files`.
+    Unexpected {
+        /// Name of the field with the extra file.
+        field: &'static str,
+
+        /// Path of the extra file.
+        path: String,
+    },
+
+    /// A file is listed with a different size in the named field than
+    /// it is in `Files`.
+    Size {
+        /// Name of the field with the wrong size.
+        field: &'static str,
+
+        /// Path of the file with the wrong size.
+        path: String,
+
+        /// Size of the file according to `Files`.
+        expected: usize,
+
+        /// Size of the file according to the named field.
+        found: usize,
+    },
+}
+crate::errors::error_enum!(FileListMismatch);
+
+fn verify_file_list<'a>(
+    field: &'static str,
+    expected: &[(&str, usize)],
+    found: impl Iterator<Item = (&'a str, usize)>,
+    mismatches: &mut Vec<FileListMismatch>,
+) {
+    let found = found.collect::<Vec<_>>();
+
+    for (path, size) in expected {
+        match found.iter().find(|(found_path, _)| found_path == path) {
+            None => mismatches.push(FileListMismatch::Missing {
+                field,
+                path: path.to_string(),
+            }),
+            Some((_, found_size)) if found_size != size => {
+                mismatches.push(FileListMismatch::Size {
+                    field,
+                    path: path.to_string(),
+                    expected: *size,
+                    found: *found_size,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (path, _) in &found {
+        if !expected
+            .iter()
+            .any(|(expected_path, _)| expected_path == path)
+        {
+            mismatches.push(FileListMismatch::Unexpected {
+                field,
+                path: path.to_string(),
+            });
+        }
+    }
+}
+
 /// When preparing a package upload for Debian, the source package's
 /// manifest is generated in the form of a `.dsc`.
 ///
@@ -101,7 +186,7 @@ pub struct Dsc {
     /// Note: The MD5 checksum is considered weak, and should never be assumed
     /// to be sufficient for secure verification, but this field cannot be
     /// omitted as it provides metadata not available anywhere else.
-    pub files: Vec<FileDigestMd5>,
+    pub files: Files<FileDigestMd5>,
 
     /// Each line consists of space-separated entries describing the file:
     /// the checksum, the file size, and the file name.
@@ -125,4 +210,345 @@ pub struct Dsc {
     pub checksum_sha256: Vec<FileDigestSha256>,
 }
 
+#[cfg(feature = "serde")]
+impl crate::control::ser::FieldOrder for Dsc {
+    const FIELD_ORDER: &'static [&'static str] = &[
+        "Format",
+        "Source",
+        "Binary",
+        "Architecture",
+        "Version",
+        "Maintainer",
+        "Uploaders",
+        "Homepage",
+        "Description",
+        "Standards-Version",
+        "Vcs-Browser",
+        "Vcs-Arch",
+        "Vcs-Bzr",
+        "Vcs-Cvs",
+        "Vcs-Darcs",
+        "Vcs-Git",
+        "Vcs-Hg",
+        "Vcs-Mtn",
+        "Vcs-Svn",
+        "Dgit",
+        "Testsuite",
+        "Testsuite-Triggers",
+        "Build-Depends",
+        "Build-Depends-Arch",
+        "Build-Depends-Indep",
+        "Build-Conflicts",
+        "Build-Conflicts-Arch",
+        "Build-Conflicts-Indep",
+        "Package-List",
+        "Checksums-Sha1",
+        "Checksums-Sha256",
+        "Files",
+    ];
+}
+
+impl Dsc {
+    /// Return `true` if the `Testsuite` field declares any kind of
+    /// `autopkgtest`, either shipped by the package itself or generated
+    /// by `autodep8` (`autopkgtest-pkg-*`).
+    pub fn has_autopkgtest(&self) -> bool {
+        self.control
+            .testsuite
+            .as_ref()
+            .is_some_and(|testsuites| testsuites.iter().any(|t| t.is_autopkgtest()))
+    }
+
+    /// Check that `Checksums-Sha1` (if present) and `Checksums-Sha256`
+    /// list exactly the same files as `Files`, with the same sizes. Every
+    /// disagreement found is returned, rather than just the first.
+    ///
+    /// This doesn't check any of the digests against the files themselves.
+    pub fn verify_file_lists(&self) -> Result<(), Vec<FileListMismatch>> {
+        let expected = self
+            .files
+            .iter()
+            .map(|file| (file.path.as_str(), file.size))
+            .collect::<Vec<_>>();
+        let mut mismatches = vec![];
+
+        if let Some(checksum_sha1) = &self.checksum_sha1 {
+            verify_file_list(
+                "Checksums-Sha1",
+                &expected,
+                checksum_sha1
+                    .iter()
+                    .map(|file| (file.path.as_str(), file.size)),
+                &mut mismatches,
+            );
+        }
+
+        verify_file_list(
+            "Checksums-Sha256",
+            &expected,
+            self.checksum_sha256
+                .iter()
+                .map(|file| (file.path.as_str(), file.size)),
+            &mut mismatches,
+        );
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches)
+        }
+    }
+
+    /// Parse the `Standards-Version` field, if present, into a
+    /// [StandardsVersion] which can be compared against some other
+    /// version of Debian Policy.
+    pub fn standards_version(
+        &self,
+    ) -> Result<Option<StandardsVersion>, StandardsVersionParseError> {
+        self.control
+            .standards_version
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Parse the `Testsuite-Triggers` field, if present, into a
+    /// [Dependency] listing the packages which should cause this source
+    /// package's tests to be run again when they change. This uses the
+    /// same relationship syntax as `Depends`, although alternatives are
+    /// flattened out, so there aren't any in practice.
+    pub fn testsuite_triggers(&self) -> Result<Option<Dependency>, dependency::Error> {
+        self.control
+            .testsuite_triggers
+            .as_ref()
+            .map(|triggers| triggers.join(", ").parse())
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use crate::control::{de, package::Testsuite, ser};
+
+        macro_rules! test_dsc {
+            ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
+                #[test]
+                fn $name() {
+                    let $parsed = de::from_str::<Dsc>($data).unwrap();
+                    $block
+                }
+            };
+        }
+
+        const HELLO_DSC: &str = "\
+Format: 3.0 (quilt)
+Source: hello
+Binary: hello
+Architecture: any
+Version: 2.10-3
+Maintainer: Santiago Vila <sanvila@debian.org>
+Homepage: https://www.gnu.org/software/hello/
+Standards-Version: 4.6.2
+Vcs-Browser: https://salsa.debian.org/sanvila/hello
+Vcs-Git: https://salsa.debian.org/sanvila/hello.git
+Testsuite: autopkgtest
+Build-Depends: debhelper-compat (= 13), help2man, texinfo
+Package-List:
+ hello deb devel optional arch=any
+Checksums-Sha1:
+ f7bebf6f9c62a2295e889f66e05ce9bfaed9ace3 725946 hello_2.10.orig.tar.gz
+ 9dc7a584db576910856ac7aa5cffbaeefe9cf427 819 hello_2.10.orig.tar.gz.asc
+ 82e477ec77f09bae910e53592d28319774754af6 12688 hello_2.10-3.debian.tar.xz
+Checksums-Sha256:
+ 31e066137a962676e89f69d1b65382de95a7ef7d914b8cb956f41ea72e0f516b 725946 hello_2.10.orig.tar.gz
+ 4ea69de913428a4034d30dcdcb34ab84f5c4a76acf9040f3091f0d3fac411b60 819 hello_2.10.orig.tar.gz.asc
+ f43ddcca8d7168c5d52b53e1f2a69b78f42f8387633ef8955edd0621c73cf65c 12688 hello_2.10-3.debian.tar.xz
+Files:
+ 6cd0ffea3884a4e79330338dcc2987d6 725946 hello_2.10.orig.tar.gz
+ e6074bb23a0f184e00fdfb5c546b3bc2 819 hello_2.10.orig.tar.gz.asc
+ 16678389ba7fddcdfa05e0707d61f043 12688 hello_2.10-3.debian.tar.xz
+";
+
+        test_dsc!(hello_testsuite, HELLO_DSC, |dsc| {
+            assert_eq!("hello", dsc.source);
+            assert!(dsc.has_autopkgtest());
+            assert_eq!(
+                vec![Testsuite::Autopkgtest],
+                dsc.control.testsuite.unwrap().get_ref()
+            );
+        });
+
+        test_dsc!(hello_round_trip, HELLO_DSC, |dsc| {
+            // absent fields are written out empty, and come back as
+            // `Some("")`, so the first pass isn't an identity; after that
+            // it must be.
+            let dsc: Dsc = de::from_str(&ser::to_string(&dsc).unwrap()).unwrap();
+            let encoded = ser::to_string(&dsc).unwrap();
+            assert_eq!(dsc, de::from_str::<Dsc>(&encoded).unwrap());
+            assert_eq!(encoded, ser::to_string(&dsc).unwrap());
+        });
+
+        test_dsc!(hello_policy_ordered, HELLO_DSC, |dsc| {
+            let encoded = ser::to_string_policy_ordered(&dsc).unwrap();
+            let keys = encoded
+                .lines()
+                .filter(|line| !line.starts_with(' '))
+                .filter_map(|line| line.split(':').next())
+                .collect::<Vec<_>>();
+
+            let known = keys
+                .iter()
+                .map(|key| {
+                    <Dsc as ser::FieldOrder>::FIELD_ORDER
+                        .iter()
+                        .position(|field| field == key)
+                        .unwrap()
+                })
+                .collect::<Vec<_>>();
+            assert!(known.is_sorted(), "{keys:?} isn't in policy order");
+            assert_eq!(Some(&"Files"), keys.last());
+
+            let dsc = de::from_str::<Dsc>(&encoded).unwrap();
+            assert!(dsc.has_autopkgtest());
+        });
+
+        test_dsc!(hello_standards_version, HELLO_DSC, |dsc| {
+            let standards_version = dsc.standards_version().unwrap().unwrap();
+            assert_eq!("4.6.2", standards_version.to_string());
+            assert!(standards_version < "4.7.0".parse().unwrap());
+        });
+
+        test_dsc!(
+            hello_testsuite_triggers,
+            &HELLO_DSC.replace(
+                "Testsuite: autopkgtest\n",
+                "Testsuite: autopkgtest\nTestsuite-Triggers: libfoo-dev, gcc\n",
+            ),
+            |dsc| {
+                let triggers = dsc.testsuite_triggers().unwrap().unwrap();
+                assert_eq!(2, triggers.len());
+                assert_eq!("libfoo-dev, gcc", triggers.to_string());
+                assert_eq!(
+                    vec!["libfoo-dev", "gcc"],
+                    triggers
+                        .relations
+                        .iter()
+                        .map(|relation| relation.packages[0].name.as_str())
+                        .collect::<Vec<_>>()
+                );
+            }
+        );
+
+        test_dsc!(hello_no_testsuite_triggers, HELLO_DSC, |dsc| {
+            assert!(dsc.testsuite_triggers().unwrap().is_none());
+        });
+
+        test_dsc!(
+            hello_no_standards_version,
+            &HELLO_DSC.replace("Standards-Version: 4.6.2\n", ""),
+            |dsc| {
+                assert_eq!(Ok(None), dsc.standards_version());
+            }
+        );
+
+        test_dsc!(
+            hello_bad_standards_version,
+            &HELLO_DSC.replace("Standards-Version: 4.6.2", "Standards-Version: 4.6"),
+            |dsc| {
+                assert!(dsc.standards_version().is_err());
+            }
+        );
+
+        test_dsc!(
+            hello_no_testsuite,
+            &HELLO_DSC.replace("Testsuite: autopkgtest\n", ""),
+            |dsc| {
+                assert!(!dsc.has_autopkgtest());
+            }
+        );
+
+        test_dsc!(
+            hello_autodep8_testsuite,
+            &HELLO_DSC.replace("Testsuite: autopkgtest", "Testsuite: autopkgtest-pkg-perl"),
+            |dsc| {
+                assert!(dsc.has_autopkgtest());
+            }
+        );
+
+        test_dsc!(hello_file_lists, HELLO_DSC, |dsc| {
+            assert_eq!(Ok(()), dsc.verify_file_lists());
+        });
+
+        test_dsc!(
+            hello_file_lists_no_sha1,
+            &HELLO_DSC.replace(
+                "\
+Checksums-Sha1:
+ f7bebf6f9c62a2295e889f66e05ce9bfaed9ace3 725946 hello_2.10.orig.tar.gz
+ 9dc7a584db576910856ac7aa5cffbaeefe9cf427 819 hello_2.10.orig.tar.gz.asc
+ 82e477ec77f09bae910e53592d28319774754af6 12688 hello_2.10-3.debian.tar.xz
+",
+                ""
+            ),
+            |dsc| {
+                assert!(dsc.checksum_sha1.is_none());
+                assert_eq!(Ok(()), dsc.verify_file_lists());
+            }
+        );
+
+        test_dsc!(
+            hello_file_lists_mismatch,
+            &HELLO_DSC
+                .replace(
+                    "82e477ec77f09bae910e53592d28319774754af6 12688 hello_2.10-3.debian.tar.xz",
+                    "82e477ec77f09bae910e53592d28319774754af6 12689 hello_2.10-3.debian.tar.xz",
+                )
+                .replace(
+                    " 4ea69de913428a4034d30dcdcb34ab84f5c4a76acf9040f3091f0d3fac411b60 819 hello_2.10.orig.tar.gz.asc\n",
+                    "",
+                )
+                .replace("12688 hello_2.10-3.debian.tar.xz\nFiles", "12688 hello_2.10-4.debian.tar.xz\nFiles"),
+            |dsc| {
+                assert_eq!(
+                    Err(vec![
+                        FileListMismatch::Size {
+                            field: "Checksums-Sha1",
+                            path: "hello_2.10-3.debian.tar.xz".to_owned(),
+                            expected: 12688,
+                            found: 12689,
+                        },
+                        FileListMismatch::Missing {
+                            field: "Checksums-Sha256",
+                            path: "hello_2.10.orig.tar.gz.asc".to_owned(),
+                        },
+                        FileListMismatch::Missing {
+                            field: "Checksums-Sha256",
+                            path: "hello_2.10-3.debian.tar.xz".to_owned(),
+                        },
+                        FileListMismatch::Unexpected {
+                            field: "Checksums-Sha256",
+                            path: "hello_2.10-4.debian.tar.xz".to_owned(),
+                        },
+                    ]),
+                    dsc.verify_file_lists()
+                );
+            }
+        );
+
+        test_dsc!(
+            hello_other_testsuite,
+            &HELLO_DSC.replace("Testsuite: autopkgtest", "Testsuite: something-else"),
+            |dsc| {
+                assert!(!dsc.has_autopkgtest());
+            }
+        );
+    }
+}
+
 // vim: foldmethod=marker
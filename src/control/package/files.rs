@@ -0,0 +1,208 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::File;
+use crate::control::FileDigestMd5;
+use std::{ops::Deref, str::FromStr};
+
+/// An entry in a `Files` field, which knows how many space separated
+/// columns make up one of its lines.
+pub trait FilesEntry: FromStr {
+    /// Number of space separated columns in a line describing this entry.
+    const COLUMNS: usize;
+}
+
+impl FilesEntry for File {
+    const COLUMNS: usize = 5;
+}
+
+impl FilesEntry for FileDigestMd5 {
+    const COLUMNS: usize = 3;
+}
+
+/// The one-line-per-entry `Files` field. In a
+/// [crate::control::package::Changes] file each line is a [File] (md5sum,
+/// size, section, priority and name), and in a
+/// [crate::control::package::Dsc] it is a [FileDigestMd5] (md5sum, size
+/// and name).
+///
+/// As with every `Files` field, the MD5 checksum is considered weak, and
+/// should never be assumed to be sufficient for secure verification.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Files<EntryT>(pub Vec<EntryT>);
+
+/// Error conditions which may be encountered when parsing a line of a
+/// [Files] field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilesParseError<ErrT> {
+    /// The line didn't have the number of columns the entry type expects,
+    /// such as a `.dsc` line being parsed as a `.changes` line.
+    WrongColumnCount {
+        /// Number of columns an entry of this type has.
+        expected: usize,
+
+        /// Number of columns found on the line.
+        found: usize,
+    },
+
+    /// The line had the right number of columns, but the entry itself
+    /// couldn't be parsed.
+    InvalidEntry(ErrT),
+}
+
+impl<ErrT: std::fmt::Debug> std::fmt::Display for FilesParseError<ErrT> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl<ErrT: std::fmt::Debug> std::error::Error for FilesParseError<ErrT> {}
+
+impl<EntryT> Files<EntryT>
+where
+    EntryT: FilesEntry,
+{
+    /// Parse a single line of a `Files` field, checking that it has the
+    /// number of columns that `EntryT` expects before parsing it.
+    pub fn parse_line(line: &str) -> Result<EntryT, FilesParseError<EntryT::Err>> {
+        let columns = line.split_ascii_whitespace().collect::<Vec<_>>();
+        if columns.len() != EntryT::COLUMNS {
+            return Err(FilesParseError::WrongColumnCount {
+                expected: EntryT::COLUMNS,
+                found: columns.len(),
+            });
+        }
+        columns
+            .join(" ")
+            .parse()
+            .map_err(FilesParseError::InvalidEntry)
+    }
+}
+
+impl<EntryT> Deref for Files<EntryT> {
+    type Target = [EntryT];
+    fn deref(&self) -> &[EntryT] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::{Files, FilesEntry};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    impl<EntryT> Serialize for Files<EntryT>
+    where
+        EntryT: std::fmt::Display,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<_>>()
+                .serialize(serializer)
+        }
+    }
+
+    impl<'de, EntryT> Deserialize<'de> for Files<EntryT>
+    where
+        EntryT: FilesEntry,
+        EntryT::Err: std::fmt::Debug,
+    {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let lines = Vec::<String>::deserialize(d)?;
+            Ok(Files(
+                lines
+                    .iter()
+                    .map(|line| Files::parse_line(line))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| D::Error::custom(format!("{e:?}")))?,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::package::ChangesParseError;
+
+    #[test]
+    fn test_parse_line_changes() {
+        let file = Files::<File>::parse_line(
+            "16678389ba7fddcdfa05e0707d61f043 12688 devel optional hello_2.10-3.debian.tar.xz",
+        )
+        .unwrap();
+        assert_eq!("devel", file.section);
+        assert_eq!("hello_2.10-3.debian.tar.xz", file.path);
+    }
+
+    #[test]
+    fn test_parse_line_dsc() {
+        let file = Files::<FileDigestMd5>::parse_line(
+            "16678389ba7fddcdfa05e0707d61f043  12688 hello_2.10-3.debian.tar.xz",
+        )
+        .unwrap();
+        assert_eq!(12688, file.size);
+        assert_eq!("hello_2.10-3.debian.tar.xz", file.path);
+    }
+
+    #[test]
+    fn test_parse_line_dsc_as_changes() {
+        assert_eq!(
+            Err(FilesParseError::WrongColumnCount {
+                expected: 5,
+                found: 3
+            }),
+            Files::<File>::parse_line(
+                "16678389ba7fddcdfa05e0707d61f043 12688 hello_2.10-3.debian.tar.xz"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_line_changes_as_dsc() {
+        assert_eq!(
+            Err(FilesParseError::WrongColumnCount {
+                expected: 3,
+                found: 5
+            }),
+            Files::<FileDigestMd5>::parse_line(
+                "16678389ba7fddcdfa05e0707d61f043 12688 devel optional hello_2.10-3.debian.tar.xz"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_line_invalid_entry() {
+        assert_eq!(
+            Err(FilesParseError::InvalidEntry(ChangesParseError::Malformed)),
+            Files::<File>::parse_line(
+                "16678389ba7fddcdfa05e0707d61f043 big devel optional hello_2.10-3.debian.tar.xz"
+            )
+        );
+    }
+}
+
+// vim: foldmethod=marker
@@ -26,18 +26,28 @@ mod changes;
 mod common_source_control;
 mod dsc;
 mod file;
+mod files;
 mod package_list;
+mod package_type;
+mod rules_requires_root;
 mod source_control;
 mod source_name;
+mod standards_version;
+mod testsuite;
 
 pub use binary_control::BinaryControl;
-pub use buildinfo::Buildinfo;
-pub use changes::{Changes, ChangesParseError};
+pub use buildinfo::{BuildDepsDiff, BuildEnvironment, BuildEnvironmentParseError, Buildinfo};
+pub use changes::{Changes, ChangesParseError, ChangesParseOptions, UploadKind};
 pub use common_source_control::CommonSourceControl;
-pub use dsc::{Dsc, DscParseError};
+pub use dsc::{Dsc, DscParseError, FileListMismatch};
 pub use file::File;
+pub use files::{Files, FilesEntry, FilesParseError};
 pub use package_list::PackageList;
+pub use package_type::{PackageType, PackageTypeParseError};
+pub use rules_requires_root::{RulesRequiresRoot, RulesRequiresRootParseError};
 pub use source_control::SourceControl;
 pub use source_name::{SourceName, SourceNameError};
+pub use standards_version::{StandardsVersion, StandardsVersionParseError};
+pub use testsuite::{Testsuite, TestsuiteParseError, Testsuites};
 
 // vim: foldmethod=marker
@@ -18,7 +18,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
-use super::DscParseError;
+use super::{DscParseError, PackageType};
 use crate::control::{Priority, def_serde_traits_for};
 use std::str::FromStr;
 
@@ -45,7 +45,7 @@ pub struct PackageList {
 
     /// Indicate the type of package: deb for binary packages and udeb for
     /// micro binary packages. Other types not defined here may be indicated.
-    pub binary_type: String,
+    pub binary_type: PackageType,
 
     /// Section of the archive to target.
     pub section: String,
@@ -80,7 +80,7 @@ impl FromStr for PackageList {
 
         Ok(Self {
             name,
-            binary_type,
+            binary_type: binary_type.parse().map_err(|_| DscParseError::Malformed)?,
             section,
             priority: priority.parse().map_err(DscParseError::InvalidPriority)?,
         })
@@ -105,6 +105,12 @@ mod tests {
         check_with_arch,
         "ocaml-doc deb non-free/doc optional arch=all"
     );
+
+    #[test]
+    fn check_udeb() {
+        let package: PackageList = "hello-udeb udeb debian-installer optional".parse().unwrap();
+        assert_eq!(PackageType::Udeb, package.binary_type);
+    }
 }
 
 // vim: foldmethod=marker
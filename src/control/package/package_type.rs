@@ -0,0 +1,102 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use crate::control::def_serde_traits_for;
+use std::str::FromStr;
+
+/// Type of a binary package, as set by the `Package-Type` field or in a
+/// `Package-List` entry.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub enum PackageType {
+    /// A regular binary package. This is assumed if no type is given.
+    #[default]
+    Deb,
+
+    /// A micro binary package, used by the Debian Installer. These are
+    /// stripped down, and aren't meant to be installed on a regular
+    /// system.
+    Udeb,
+
+    /// Some package type which isn't known to this crate.
+    Other(String),
+}
+
+def_serde_traits_for!(PackageType);
+
+/// Error conditions which may be encountered when working with a
+/// [PackageType].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PackageTypeParseError {
+    /// The [PackageType] was empty.
+    Empty,
+}
+crate::errors::error_enum!(PackageTypeParseError);
+
+impl FromStr for PackageType {
+    type Err = PackageTypeParseError;
+
+    fn from_str(package_type: &str) -> Result<Self, Self::Err> {
+        Ok(match package_type {
+            "" => return Err(PackageTypeParseError::Empty),
+            "deb" => Self::Deb,
+            "udeb" => Self::Udeb,
+            _ => Self::Other(package_type.to_owned()),
+        })
+    }
+}
+
+impl std::fmt::Display for PackageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Deb => write!(f, "deb"),
+            Self::Udeb => write!(f, "udeb"),
+            Self::Other(package_type) => write!(f, "{package_type}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::{def_failing_parse_test, def_parse_test};
+
+    def_parse_test!(parse_deb, PackageType, "deb", PackageType::Deb);
+    def_parse_test!(parse_udeb, PackageType, "udeb", PackageType::Udeb);
+    def_parse_test!(
+        parse_other,
+        PackageType,
+        "ddeb",
+        PackageType::Other("ddeb".to_owned())
+    );
+
+    def_failing_parse_test!(fail_empty, PackageType, "");
+
+    #[test]
+    fn round_trip() {
+        for package_type in ["deb", "udeb", "ddeb"] {
+            assert_eq!(
+                package_type,
+                package_type.parse::<PackageType>().unwrap().to_string()
+            );
+        }
+    }
+}
+
+// vim: foldmethod=marker
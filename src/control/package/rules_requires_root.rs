@@ -0,0 +1,136 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use crate::control::def_serde_traits_for;
+use std::str::FromStr;
+
+/// Declares if the source package needs `root` (or `fakeroot`) to run
+/// any of the targets in `debian/rules`, as found in the
+/// `Rules-Requires-Root` field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RulesRequiresRoot {
+    /// `no`: none of the targets require root.
+    No,
+
+    /// `binary-targets`: the `binary` targets need to be run as
+    /// (fake)root. This is the default if the field is missing.
+    BinaryTargets,
+
+    /// Space separated list of keywords (such as
+    /// `dpkg/target-subcommand`) which declare exactly when (fake)root is
+    /// required.
+    Keywords(Vec<String>),
+}
+
+def_serde_traits_for!(RulesRequiresRoot);
+
+/// Error conditions which may be encountered when working with a
+/// [RulesRequiresRoot].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RulesRequiresRootParseError {
+    /// The [RulesRequiresRoot] was empty.
+    Empty,
+
+    /// `no` or `binary-targets` was given along with other keywords,
+    /// which isn't allowed.
+    Malformed,
+}
+crate::errors::error_enum!(RulesRequiresRootParseError);
+
+impl FromStr for RulesRequiresRoot {
+    type Err = RulesRequiresRootParseError;
+
+    fn from_str(rrr: &str) -> Result<Self, Self::Err> {
+        let keywords = rrr.split_ascii_whitespace().collect::<Vec<_>>();
+        Ok(match keywords[..] {
+            [] => return Err(RulesRequiresRootParseError::Empty),
+            ["no"] => Self::No,
+            ["binary-targets"] => Self::BinaryTargets,
+            _ if keywords.contains(&"no") || keywords.contains(&"binary-targets") => {
+                return Err(RulesRequiresRootParseError::Malformed);
+            }
+            _ => Self::Keywords(keywords.into_iter().map(|k| k.to_owned()).collect()),
+        })
+    }
+}
+
+impl std::fmt::Display for RulesRequiresRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::No => write!(f, "no"),
+            Self::BinaryTargets => write!(f, "binary-targets"),
+            Self::Keywords(keywords) => write!(f, "{}", keywords.join(" ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::{def_failing_parse_test, def_parse_test};
+
+    def_parse_test!(parse_no, RulesRequiresRoot, "no", RulesRequiresRoot::No);
+    def_parse_test!(
+        parse_binary_targets,
+        RulesRequiresRoot,
+        "binary-targets",
+        RulesRequiresRoot::BinaryTargets
+    );
+    def_parse_test!(
+        parse_keyword,
+        RulesRequiresRoot,
+        "dpkg/target-subcommand",
+        RulesRequiresRoot::Keywords(vec!["dpkg/target-subcommand".to_owned()])
+    );
+    def_parse_test!(
+        parse_keywords,
+        RulesRequiresRoot,
+        "dpkg/target-subcommand  debputy/deb-assembly",
+        RulesRequiresRoot::Keywords(vec![
+            "dpkg/target-subcommand".to_owned(),
+            "debputy/deb-assembly".to_owned()
+        ])
+    );
+
+    def_failing_parse_test!(fail_empty, RulesRequiresRoot, "");
+    def_failing_parse_test!(fail_whitespace, RulesRequiresRoot, "  ");
+    def_failing_parse_test!(fail_no_and_keyword, RulesRequiresRoot, "no dpkg/foo");
+    def_failing_parse_test!(
+        fail_binary_targets_and_keyword,
+        RulesRequiresRoot,
+        "dpkg/foo binary-targets"
+    );
+
+    macro_rules! check_round_trips {
+        ($name:ident, $rrr:expr) => {
+            #[test]
+            fn $name() {
+                let rrr: RulesRequiresRoot = $rrr.parse().unwrap();
+                assert_eq!($rrr, rrr.to_string());
+            }
+        };
+    }
+
+    check_round_trips!(rt_no, "no");
+    check_round_trips!(rt_binary_targets, "binary-targets");
+    check_round_trips!(rt_keywords, "dpkg/target-subcommand debputy/deb-assembly");
+}
+
+// vim: foldmethod=marker
@@ -18,7 +18,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
 // THE SOFTWARE. }}}
 
-use super::{CommonSourceControl, PackageList};
+use super::{CommonSourceControl, Files, PackageList, RulesRequiresRoot};
 use crate::control::{FileDigestMd5, FileDigestSha1, FileDigestSha256};
 
 #[cfg(feature = "serde")]
@@ -52,6 +52,18 @@ pub struct SourceControl {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub control: CommonSourceControl,
 
+    /// Declares if `debian/rules` needs to be run as (fake)root. If
+    /// missing, this should be treated as
+    /// [RulesRequiresRoot::BinaryTargets].
+    #[cfg_attr(feature = "serde", serde(rename = "Rules-Requires-Root"))]
+    pub rules_requires_root: Option<RulesRequiresRoot>,
+
+    /// Set to `yes` by the archive if this source package is only kept
+    /// around because some binary package still needs it (for instance,
+    /// a `Built-Using` reference), and isn't otherwise part of the suite.
+    #[cfg_attr(feature = "serde", serde(rename = "Extra-Source-Only"))]
+    pub extra_source_only: Option<bool>,
+
     /// list of binary packages generated by this source package.
     #[cfg_attr(feature = "serde", serde(rename = "Package-List"))]
     pub package_list: Option<Vec<PackageList>>,
@@ -70,7 +82,7 @@ pub struct SourceControl {
     /// Note: The MD5 checksum is considered weak, and should never be assumed
     /// to be sufficient for secure verification, but this field cannot be
     /// omitted as it provides metadata not available anywhere else.
-    pub files: Vec<FileDigestMd5>,
+    pub files: Files<FileDigestMd5>,
 
     /// Each line consists of space-separated entries describing the file:
     /// the checksum, the file size, and the file name.
@@ -94,4 +106,147 @@ pub struct SourceControl {
     pub checksum_sha256: Vec<FileDigestSha256>,
 }
 
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+        use crate::control::{de, package::Testsuite};
+
+        macro_rules! test_source_control {
+            ($name:ident, $data:expr, |$parsed:ident| $block:tt) => {
+                #[test]
+                fn $name() {
+                    let $parsed = de::from_str::<SourceControl>($data).unwrap();
+                    $block
+                }
+            };
+        }
+
+        test_source_control!(
+            hello_source_control_source,
+            "\
+Package: hello
+Format: 3.0 (quilt)
+Binary: hello
+Architecture: any
+Version: 2.10-3
+Maintainer: Santiago Vila <sanvila@debian.org>
+Homepage: https://www.gnu.org/software/hello/
+Standards-Version: 4.6.2
+Rules-Requires-Root: no
+Vcs-Browser: https://salsa.debian.org/sanvila/hello
+Vcs-Git: https://salsa.debian.org/sanvila/hello.git
+Testsuite: autopkgtest
+Build-Depends: debhelper-compat (= 13), help2man, texinfo
+Package-List:
+ hello deb devel optional arch=any
+Checksums-Sha256:
+ 31e066137a962676e89f69d1b65382de95a7ef7d914b8cb956f41ea72e0f516b 725946 hello_2.10.orig.tar.gz
+ 4ea69de913428a4034d30dcdcb34ab84f5c4a76acf9040f3091f0d3fac411b60 819 hello_2.10.orig.tar.gz.asc
+ f43ddcca8d7168c5d52b53e1f2a69b78f42f8387633ef8955edd0621c73cf65c 12688 hello_2.10-3.debian.tar.xz
+Files:
+ 6cd0ffea3884a4e79330338dcc2987d6 725946 hello_2.10.orig.tar.gz
+ e6074bb23a0f184e00fdfb5c546b3bc2 819 hello_2.10.orig.tar.gz.asc
+ 16678389ba7fddcdfa05e0707d61f043 12688 hello_2.10-3.debian.tar.xz
+",
+            |source| {
+                assert_eq!("hello", source.package);
+                assert_eq!(Some(RulesRequiresRoot::No), source.rules_requires_root);
+                assert_eq!(
+                    vec![Testsuite::Autopkgtest],
+                    source.control.testsuite.unwrap().get_ref()
+                );
+            }
+        );
+
+        test_source_control!(
+            testsuite_multiple,
+            "\
+Package: hello
+Format: 3.0 (quilt)
+Version: 2.10-3
+Maintainer: Santiago Vila <sanvila@debian.org>
+Testsuite: autopkgtest, autopkgtest-pkg-python
+Checksums-Sha256:
+ 31e066137a962676e89f69d1b65382de95a7ef7d914b8cb956f41ea72e0f516b 725946 hello_2.10.orig.tar.gz
+Files:
+ 6cd0ffea3884a4e79330338dcc2987d6 725946 hello_2.10.orig.tar.gz
+",
+            |source| {
+                assert_eq!(
+                    vec![
+                        Testsuite::Autopkgtest,
+                        Testsuite::AutopkgtestPkg("python".to_owned())
+                    ],
+                    source.control.testsuite.unwrap().get_ref()
+                );
+            }
+        );
+
+        test_source_control!(
+            testsuite_missing,
+            "\
+Package: hello
+Format: 3.0 (quilt)
+Version: 2.10-3
+Maintainer: Santiago Vila <sanvila@debian.org>
+Checksums-Sha256:
+ 31e066137a962676e89f69d1b65382de95a7ef7d914b8cb956f41ea72e0f516b 725946 hello_2.10.orig.tar.gz
+Files:
+ 6cd0ffea3884a4e79330338dcc2987d6 725946 hello_2.10.orig.tar.gz
+",
+            |source| {
+                assert!(source.control.testsuite.is_none());
+                assert!(source.rules_requires_root.is_none());
+                assert!(source.extra_source_only.is_none());
+            }
+        );
+
+        test_source_control!(
+            extra_source_only,
+            "\
+Package: hello
+Format: 3.0 (quilt)
+Version: 2.10-3
+Maintainer: Santiago Vila <sanvila@debian.org>
+Extra-Source-Only: yes
+Checksums-Sha256:
+ 31e066137a962676e89f69d1b65382de95a7ef7d914b8cb956f41ea72e0f516b 725946 hello_2.10.orig.tar.gz
+Files:
+ 6cd0ffea3884a4e79330338dcc2987d6 725946 hello_2.10.orig.tar.gz
+",
+            |source| {
+                assert_eq!(Some(true), source.extra_source_only);
+            }
+        );
+
+        test_source_control!(
+            rules_requires_root_keywords,
+            "\
+Package: hello
+Format: 3.0 (quilt)
+Version: 2.10-3
+Maintainer: Santiago Vila <sanvila@debian.org>
+Rules-Requires-Root: dpkg/target-subcommand
+Checksums-Sha256:
+ 31e066137a962676e89f69d1b65382de95a7ef7d914b8cb956f41ea72e0f516b 725946 hello_2.10.orig.tar.gz
+Files:
+ 6cd0ffea3884a4e79330338dcc2987d6 725946 hello_2.10.orig.tar.gz
+",
+            |source| {
+                assert_eq!(
+                    Some(RulesRequiresRoot::Keywords(vec![
+                        "dpkg/target-subcommand".to_owned()
+                    ])),
+                    source.rules_requires_root
+                );
+            }
+        );
+    }
+}
+
 // vim: foldmethod=marker
@@ -0,0 +1,263 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use crate::control::def_serde_traits_for;
+use std::{cmp::Ordering, str::FromStr};
+
+/// Version of the Debian Policy Manual that a source package complies
+/// with, as found in the `Standards-Version` field (such as `4.6.2`).
+///
+/// This is *not* a Debian [crate::version::Version] -- it's a dotted list
+/// of three or four numbers: the major and minor version, and the major
+/// and minor patch level. The minor patch level is only bumped for
+/// editorial changes, and is often left off, so `4.6.2` and `4.6.2.0`
+/// compare as equal.
+#[derive(Copy, Clone, Debug)]
+pub struct StandardsVersion {
+    /// Major version, changed when every package needs to be updated.
+    pub major: u32,
+
+    /// Minor version, changed when significant changes require work in
+    /// many packages.
+    pub minor: u32,
+
+    /// Major patch level, changed for any change to the meaning of the
+    /// standards.
+    pub major_patch: u32,
+
+    /// Minor patch level, changed for cosmetic or editorial changes
+    /// only. This is `None` if the field only had three components.
+    pub minor_patch: Option<u32>,
+}
+
+def_serde_traits_for!(StandardsVersion);
+
+/// Error conditions which may be encountered when working with a
+/// [StandardsVersion].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StandardsVersionParseError {
+    /// The [StandardsVersion] was empty.
+    Empty,
+
+    /// The [StandardsVersion] didn't have three or four components.
+    WrongLength,
+
+    /// One of the components wasn't a non-negative integer.
+    InvalidNumber,
+}
+crate::errors::error_enum!(StandardsVersionParseError);
+
+impl StandardsVersion {
+    /// Latest version of the Debian Policy Manual known to this crate.
+    ///
+    /// Like the release data in [crate::release], this is best-effort
+    /// static data, and is updated alongside new releases of Debian Policy
+    /// -- so an old build of this crate may not know about the most recent
+    /// version.
+    pub const LATEST: StandardsVersion = StandardsVersion {
+        major: 4,
+        minor: 7,
+        major_patch: 2,
+        minor_patch: None,
+    };
+
+    /// Return `true` if this [StandardsVersion] is older than
+    /// [StandardsVersion::LATEST], meaning the package hasn't been checked
+    /// against the most recent version of Debian Policy.
+    ///
+    /// ```
+    /// use deb::control::package::StandardsVersion;
+    ///
+    /// let standards_version: StandardsVersion = "4.6.2".parse().unwrap();
+    /// assert!(standards_version.is_outdated());
+    /// assert!(!StandardsVersion::LATEST.is_outdated());
+    /// ```
+    pub fn is_outdated(&self) -> bool {
+        *self < Self::LATEST
+    }
+
+    fn components(&self) -> [u32; 4] {
+        [
+            self.major,
+            self.minor,
+            self.major_patch,
+            self.minor_patch.unwrap_or(0),
+        ]
+    }
+}
+
+impl PartialEq for StandardsVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.components() == other.components()
+    }
+}
+
+impl Eq for StandardsVersion {}
+
+impl PartialOrd for StandardsVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StandardsVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.components().cmp(&other.components())
+    }
+}
+
+impl FromStr for StandardsVersion {
+    type Err = StandardsVersionParseError;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        if version.is_empty() {
+            return Err(StandardsVersionParseError::Empty);
+        }
+
+        let components = version
+            .split('.')
+            .map(|component| {
+                if component.is_empty() || !component.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(StandardsVersionParseError::InvalidNumber);
+                }
+                component
+                    .parse::<u32>()
+                    .map_err(|_| StandardsVersionParseError::InvalidNumber)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match components[..] {
+            [major, minor, major_patch] => Ok(Self {
+                major,
+                minor,
+                major_patch,
+                minor_patch: None,
+            }),
+            [major, minor, major_patch, minor_patch] => Ok(Self {
+                major,
+                minor,
+                major_patch,
+                minor_patch: Some(minor_patch),
+            }),
+            _ => Err(StandardsVersionParseError::WrongLength),
+        }
+    }
+}
+
+impl std::fmt::Display for StandardsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.major_patch)?;
+        if let Some(minor_patch) = self.minor_patch {
+            write!(f, ".{minor_patch}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::{def_failing_parse_test, def_parse_test};
+
+    def_parse_test!(
+        parse_three,
+        StandardsVersion,
+        "4.6.2",
+        StandardsVersion {
+            major: 4,
+            minor: 6,
+            major_patch: 2,
+            minor_patch: None,
+        }
+    );
+    def_parse_test!(
+        parse_four,
+        StandardsVersion,
+        "3.9.8.1",
+        StandardsVersion {
+            major: 3,
+            minor: 9,
+            major_patch: 8,
+            minor_patch: Some(1),
+        }
+    );
+
+    def_failing_parse_test!(fail_empty, StandardsVersion, "");
+    def_failing_parse_test!(fail_short, StandardsVersion, "4.6");
+    def_failing_parse_test!(fail_long, StandardsVersion, "4.6.2.0.1");
+    def_failing_parse_test!(fail_empty_component, StandardsVersion, "4..2");
+    def_failing_parse_test!(fail_letters, StandardsVersion, "4.6.x");
+    def_failing_parse_test!(fail_sign, StandardsVersion, "4.+6.2");
+    def_failing_parse_test!(fail_space, StandardsVersion, "4.6.2 ");
+
+    macro_rules! check_cmp {
+        ($name:ident, $left:expr, $ord:ident, $right:expr) => {
+            #[test]
+            fn $name() {
+                let left: StandardsVersion = $left.parse().unwrap();
+                let right: StandardsVersion = $right.parse().unwrap();
+                assert_eq!(Ordering::$ord, left.cmp(&right));
+            }
+        };
+    }
+
+    check_cmp!(cmp_patch_older, "4.6.2", Less, "4.7.0");
+    check_cmp!(cmp_major, "3.9.8", Less, "4.0.0");
+    check_cmp!(cmp_numeric, "4.10.0", Greater, "4.9.0");
+    check_cmp!(cmp_same, "4.7.0", Equal, "4.7.0");
+    check_cmp!(cmp_minor_patch, "4.1.4.1", Greater, "4.1.4");
+    check_cmp!(cmp_minor_patch_zero, "4.6.2.0", Equal, "4.6.2");
+
+    #[test]
+    fn is_outdated() {
+        let standards_version: StandardsVersion = "4.6.2".parse().unwrap();
+        assert!(standards_version < StandardsVersion::LATEST);
+        assert!(standards_version.is_outdated());
+
+        assert!(!StandardsVersion::LATEST.is_outdated());
+        let newer = StandardsVersion {
+            major: StandardsVersion::LATEST.major + 1,
+            ..StandardsVersion::LATEST
+        };
+        assert!(!newer.is_outdated());
+
+        // an editorial release on top of the latest isn't outdated either.
+        let editorial = StandardsVersion {
+            minor_patch: Some(0),
+            ..StandardsVersion::LATEST
+        };
+        assert!(!editorial.is_outdated());
+    }
+
+    macro_rules! check_round_trips {
+        ($name:ident, $version:expr) => {
+            #[test]
+            fn $name() {
+                let version: StandardsVersion = $version.parse().unwrap();
+                assert_eq!($version, version.to_string());
+            }
+        };
+    }
+
+    check_round_trips!(rt_three, "4.6.2");
+    check_round_trips!(rt_four, "4.6.2.0");
+}
+
+// vim: foldmethod=marker
@@ -0,0 +1,161 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use crate::control::{CommaDelimitedSeq, def_serde_traits_for};
+use std::str::FromStr;
+
+/// Kind of test suite declared by a source package in the `Testsuite`
+/// field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Testsuite {
+    /// Tests defined in `debian/tests/control`, run by `autopkgtest`.
+    Autopkgtest,
+
+    /// Tests which are generated by `autodep8` for a specific type of
+    /// package, declared as `autopkgtest-pkg-<type>` (such as
+    /// `autopkgtest-pkg-python`). The contained value is the `<type>`.
+    AutopkgtestPkg(String),
+
+    /// Some test suite which isn't known to this crate.
+    Other(String),
+}
+
+def_serde_traits_for!(Testsuite);
+
+/// List of [Testsuite] values, separated with a comma. Empty entries,
+/// such as from a trailing comma, are skipped.
+pub type Testsuites = CommaDelimitedSeq<Testsuite>;
+
+/// Error conditions which may be encountered when working with a
+/// [Testsuite].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TestsuiteParseError {
+    /// The [Testsuite] was empty.
+    Empty,
+
+    /// The [Testsuite] contained whitespace, which likely means a list
+    /// was separated with spaces rather than commas.
+    Malformed,
+}
+crate::errors::error_enum!(TestsuiteParseError);
+
+impl Testsuite {
+    /// Return `true` if this is a kind of `autopkgtest`, either declared
+    /// by the package itself, or generated by `autodep8`.
+    pub fn is_autopkgtest(&self) -> bool {
+        matches!(self, Self::Autopkgtest | Self::AutopkgtestPkg(_))
+    }
+}
+
+impl FromStr for Testsuite {
+    type Err = TestsuiteParseError;
+
+    fn from_str(testsuite: &str) -> Result<Self, Self::Err> {
+        if testsuite.is_empty() {
+            return Err(TestsuiteParseError::Empty);
+        }
+        if testsuite.contains(char::is_whitespace) {
+            return Err(TestsuiteParseError::Malformed);
+        }
+
+        Ok(match testsuite {
+            "autopkgtest" => Self::Autopkgtest,
+            _ => match testsuite.strip_prefix("autopkgtest-pkg-") {
+                Some(kind) if !kind.is_empty() => Self::AutopkgtestPkg(kind.to_owned()),
+                _ => Self::Other(testsuite.to_owned()),
+            },
+        })
+    }
+}
+
+impl std::fmt::Display for Testsuite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Self::Autopkgtest => write!(f, "autopkgtest"),
+            Self::AutopkgtestPkg(kind) => write!(f, "autopkgtest-pkg-{kind}"),
+            Self::Other(testsuite) => write!(f, "{testsuite}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::{DelimitedSeq, def_failing_parse_test, def_parse_test};
+
+    def_parse_test!(
+        parse_autopkgtest,
+        Testsuite,
+        "autopkgtest",
+        Testsuite::Autopkgtest
+    );
+    def_parse_test!(
+        parse_autopkgtest_pkg,
+        Testsuite,
+        "autopkgtest-pkg-python",
+        Testsuite::AutopkgtestPkg("python".to_owned())
+    );
+    def_parse_test!(
+        parse_autopkgtest_pkg_empty,
+        Testsuite,
+        "autopkgtest-pkg-",
+        Testsuite::Other("autopkgtest-pkg-".to_owned())
+    );
+    def_parse_test!(
+        parse_other,
+        Testsuite,
+        "something",
+        Testsuite::Other("something".to_owned())
+    );
+    def_parse_test!(
+        parse_list,
+        Testsuites,
+        "autopkgtest, autopkgtest-pkg-perl",
+        DelimitedSeq(vec![
+            Testsuite::Autopkgtest,
+            Testsuite::AutopkgtestPkg("perl".to_owned())
+        ])
+    );
+    def_parse_test!(
+        parse_list_trailing_comma,
+        Testsuites,
+        "autopkgtest,",
+        DelimitedSeq(vec![Testsuite::Autopkgtest])
+    );
+
+    def_failing_parse_test!(fail_empty, Testsuite, "");
+    def_failing_parse_test!(fail_spaces, Testsuite, "autopkgtest autopkgtest-pkg-perl");
+
+    macro_rules! check_round_trips {
+        ($name:ident, $testsuite:expr) => {
+            #[test]
+            fn $name() {
+                let testsuite: Testsuite = $testsuite.parse().unwrap();
+                assert_eq!($testsuite, testsuite.to_string());
+            }
+        };
+    }
+
+    check_round_trips!(rt_autopkgtest, "autopkgtest");
+    check_round_trips!(rt_autopkgtest_pkg, "autopkgtest-pkg-nodejs");
+    check_round_trips!(rt_other, "something-else");
+}
+
+// vim: foldmethod=marker
@@ -20,6 +20,7 @@
 
 use super::pest::{Deb822Parser, Rule};
 use pest::{Parser, error::Error as PestError, iterators::Pair};
+use std::{borrow::Cow, ops::Range};
 
 /// [RawParagraph] contains all the raw, unprocessed and fully stringified
 /// [RawField] values from the underlying document. It is possible to construct
@@ -29,11 +30,16 @@ use pest::{Parser, error::Error as PestError, iterators::Pair};
 /// In general, using this directly is a bad idea. I've left it exported
 /// to help get consumers out of a bind, but that doesn't mean I won't
 /// regret that decision.
+///
+/// The [RawParagraph] borrows from the input wherever it can, to avoid
+/// copying every key and value when parsing large files (such as a
+/// `Packages` index). Use [RawParagraph::into_owned] to detach it from
+/// the input.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct RawParagraph {
+pub struct RawParagraph<'a> {
     /// Raw series of fields, in the order they were seen in the Paragraph
     /// block.
-    pub fields: Vec<RawField>,
+    pub fields: Vec<RawField<'a>>,
 }
 
 /// Minimally processed Key-Value pair from the underlying Debian RFC2822-like
@@ -42,13 +48,138 @@ pub struct RawParagraph {
 /// In general, using this directly is a bad idea. I've left it exported
 /// to help get consumers out of a bind, but that doesn't mean I won't
 /// regret that decision.
-#[derive(Clone, Debug, PartialEq)]
-pub struct RawField {
-    /// Key name for the Field
-    pub key: String,
+///
+/// Two [RawField]s are equal if their keys and values are, no matter where
+/// (or if) they were found in some input.
+#[derive(Clone, Debug)]
+pub struct RawField<'a> {
+    /// Key name for the Field. This is always borrowed from the input, so
+    /// it keeps the exact casing it was written with (`package` stays
+    /// `package`, rather than becoming `Package`), and is written back out
+    /// that way. Field names are case-insensitive, so compare keys with
+    /// [str::eq_ignore_ascii_case], as [RawParagraph::field] does.
+    pub key: Cow<'a, str>,
+
+    /// Value of the field. Values which fit on one line are borrowed from
+    /// the input, values spread across multiple lines need to be joined, and
+    /// are owned.
+    pub value: Cow<'a, str>,
+
+    span: Option<Range<usize>>,
+}
+
+impl PartialEq for RawField<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+}
 
-    /// Value of the field
-    pub value: String,
+impl<'a> RawField<'a> {
+    /// Create a new [RawField] from a key and value which weren't parsed
+    /// from any input, so it has no [RawField::span].
+    ///
+    /// ```
+    /// use deb::control::RawField;
+    ///
+    /// let field = RawField::new("Package", "hello");
+    /// assert_eq!("Package: hello", field.to_string());
+    /// assert_eq!(None, field.span());
+    /// ```
+    pub fn new(key: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+            span: None,
+        }
+    }
+}
+
+impl RawField<'_> {
+    /// Return a copy of this [RawField] which owns the key and value, rather
+    /// than borrowing from the input.
+    pub fn into_owned(self) -> RawField<'static> {
+        RawField {
+            key: Cow::Owned(self.key.into_owned()),
+            value: Cow::Owned(self.value.into_owned()),
+            span: self.span,
+        }
+    }
+
+    /// Byte offsets of this field within the input it was parsed from,
+    /// starting at the key, and running through to the end of the value
+    /// (including any continuation lines), but not the final newline.
+    ///
+    /// This is `None` if the [RawField] wasn't parsed from some input, such
+    /// as one created with [RawField::new], or if its value was rewritten
+    /// (for instance by [RawParagraph::canonicalize]).
+    ///
+    /// ```
+    /// use deb::control::RawParagraph;
+    ///
+    /// let input = "Package: hello\nVersion: 2.10-3\n";
+    /// let paragraph = RawParagraph::parse(input).unwrap();
+    /// let version = paragraph.field("Version").next().unwrap();
+    /// assert_eq!("Version: 2.10-3", &input[version.span().unwrap()]);
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+}
+
+/// Options which change how strictly a [RawParagraph] is parsed. The
+/// [Default] options are as lax as [RawParagraph::parse].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParseOptions {
+    /// Reject `simple` fields (such as `Package` or `Version`) which are
+    /// spread over more than one line, rather than treating them the same
+    /// as `folded` fields. Only fields Debian Policy defines as `simple`
+    /// are checked; unknown fields are allowed to span multiple lines.
+    pub strict_field_types: bool,
+
+    /// Reject paragraphs which contain the same field more than once.
+    /// Field names are compared without regard to case.
+    pub reject_duplicate_fields: bool,
+}
+
+/// Fields which Debian Policy defines as `simple` -- the field and its
+/// value must be on a single line.
+const SIMPLE_FIELDS: &[&str] = &[
+    "Architecture",
+    "Bugs",
+    "Changed-By",
+    "Date",
+    "Distribution",
+    "Essential",
+    "Format",
+    "Homepage",
+    "Installed-Size",
+    "Maintainer",
+    "Multi-Arch",
+    "Origin",
+    "Package",
+    "Package-Type",
+    "Priority",
+    "Rules-Requires-Root",
+    "Section",
+    "Source",
+    "Standards-Version",
+    "Urgency",
+    "Vcs-Arch",
+    "Vcs-Browser",
+    "Vcs-Bzr",
+    "Vcs-Cvs",
+    "Vcs-Darcs",
+    "Vcs-Git",
+    "Vcs-Hg",
+    "Vcs-Mtn",
+    "Vcs-Svn",
+    "Version",
+];
+
+fn is_simple_field(key: &str) -> bool {
+    SIMPLE_FIELDS
+        .iter()
+        .any(|field| field.eq_ignore_ascii_case(key))
 }
 
 /// Error conditions which may be encountered when working with a
@@ -64,6 +195,14 @@ pub enum Error {
 
     /// Something wasn't properly encoded within the Paragraph.
     Malformed,
+
+    /// A `simple` field (named here) was spread over multiple lines. This
+    /// is only returned when [ParseOptions::strict_field_types] is set.
+    MultilineSimpleField(String),
+
+    /// A field (named here) was repeated within the paragraph. This is
+    /// only returned when [ParseOptions::reject_duplicate_fields] is set.
+    DuplicateField(String),
 }
 crate::errors::error_enum!(Error);
 
@@ -73,20 +212,55 @@ impl From<PestError<Rule>> for Error {
     }
 }
 
-impl TryFrom<Pair<'_, Rule>> for RawField {
+/// Write out the [RawField] as it'd appear in a control file, with each
+/// continuation line indented by a single space.
+impl std::fmt::Display for RawField<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mut lines = self.value.split('\n');
+        match lines.next() {
+            Some("") | None => write!(f, "{}:", self.key)?,
+            Some(line) => write!(f, "{}: {}", self.key, line)?,
+        }
+        for line in lines {
+            write!(f, "\n {line}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Write out each [RawField] in the [RawParagraph], one after another.
+impl std::fmt::Display for RawParagraph<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for field in &self.fields {
+            writeln!(f, "{field}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<Pair<'a, Rule>> for RawField<'a> {
     type Error = Error;
 
-    fn try_from(token: Pair<'_, Rule>) -> Result<Self, Error> {
-        let mut key: Option<String> = None;
-        let mut value = String::new();
+    fn try_from(token: Pair<'a, Rule>) -> Result<Self, Error> {
+        // the field token can run on past the value, over any trailing
+        // comments and the newline, so the span ends with the last part of
+        // the field we actually use.
+        let start = token.as_span().start();
+        let mut end = start;
+
+        let mut key: Option<&'a str> = None;
+        let mut values: Vec<&'a str> = vec![];
 
         for part in token.into_inner() {
             match part.as_rule() {
                 Rule::field_name => {
-                    key = Some(part.as_str().to_owned());
+                    key = Some(part.as_str());
+                    // and the ':' after it.
+                    end = part.as_span().end() + 1;
                 }
                 Rule::field_value => {
-                    value.push_str(&format!("{}\n", part.as_str()));
+                    values.push(part.as_str());
+                    end = end.max(part.as_span().end());
                 }
                 _ => continue,
             };
@@ -96,17 +270,31 @@ impl TryFrom<Pair<'_, Rule>> for RawField {
             return Err(Error::Malformed);
         };
 
+        let value = match values[..] {
+            [] => Cow::Borrowed(""),
+            [value] => Cow::Borrowed(value.trim_start_matches(' ').trim_end()),
+            _ => {
+                let mut value = String::new();
+                for part in values {
+                    value.push_str(part);
+                    value.push('\n');
+                }
+                Cow::Owned(value.trim_start_matches(' ').trim_end().to_owned())
+            }
+        };
+
         Ok(RawField {
-            key,
-            value: value.trim_start_matches(' ').trim_end().to_owned(),
+            key: Cow::Borrowed(key),
+            value,
+            span: Some(start..end),
         })
     }
 }
 
-impl TryFrom<Pair<'_, Rule>> for RawParagraph {
+impl<'a> TryFrom<Pair<'a, Rule>> for RawParagraph<'a> {
     type Error = Error;
 
-    fn try_from(token: Pair<'_, Rule>) -> Result<Self, Error> {
+    fn try_from(token: Pair<'a, Rule>) -> Result<Self, Error> {
         let mut ret = Self { fields: vec![] };
         for token in token.into_inner() {
             match token.as_rule() {
@@ -122,11 +310,55 @@ impl TryFrom<Pair<'_, Rule>> for RawParagraph {
     }
 }
 
-impl RawParagraph {
+impl<'a> RawParagraph<'a> {
     /// Parse a specifically formatted block of Debian flavored RFC2822 style
     /// key/value pairs, and decode it into a Paragraph. There must be not
     /// leading or trailing spaces, nor may this span multiple paragraphs.
-    pub fn parse(paragraph: &str) -> Result<Self, Error> {
+    pub fn parse(paragraph: &'a str) -> Result<Self, Error> {
+        Self::parse_with_options(paragraph, &ParseOptions::default())
+    }
+
+    /// Parse a [RawParagraph] in the same way as [RawParagraph::parse], but
+    /// additionally check the paragraph against the provided [ParseOptions].
+    ///
+    /// ```
+    /// use deb::control::{ParseOptions, RawParagraph};
+    ///
+    /// let paragraph = "Package:\n hello\n";
+    /// assert!(RawParagraph::parse(paragraph).is_ok());
+    ///
+    /// let options = ParseOptions {
+    ///     strict_field_types: true,
+    ///     ..Default::default()
+    /// };
+    /// assert!(RawParagraph::parse_with_options(paragraph, &options).is_err());
+    /// ```
+    pub fn parse_with_options(paragraph: &'a str, options: &ParseOptions) -> Result<Self, Error> {
+        let ret = Self::parse_lax(paragraph)?;
+
+        if options.strict_field_types {
+            for field in ret.iter() {
+                if field.value.contains('\n') && is_simple_field(&field.key) {
+                    return Err(Error::MultilineSimpleField(field.key.to_string()));
+                }
+            }
+        }
+
+        if options.reject_duplicate_fields {
+            for (idx, field) in ret.fields.iter().enumerate() {
+                if ret.fields[..idx]
+                    .iter()
+                    .any(|seen| seen.key.eq_ignore_ascii_case(&field.key))
+                {
+                    return Err(Error::DuplicateField(field.key.to_string()));
+                }
+            }
+        }
+
+        Ok(ret)
+    }
+
+    fn parse_lax(paragraph: &'a str) -> Result<Self, Error> {
         let tokens = Deb822Parser::parse(Rule::single_paragraph, paragraph)?;
         let Some(token) = tokens.into_iter().next() else {
             unreachable!();
@@ -143,23 +375,35 @@ impl RawParagraph {
         Ok(Default::default())
     }
 
+    /// Return a copy of this [RawParagraph] which owns all keys and values,
+    /// rather than borrowing from the input.
+    pub fn into_owned(self) -> RawParagraph<'static> {
+        RawParagraph {
+            fields: self.fields.into_iter().map(RawField::into_owned).collect(),
+        }
+    }
+
     /// Iterate over all Key/Value pairs (as a [RawField]) in the [RawParagraph].
-    pub fn iter(&self) -> impl Iterator<Item = &RawField> {
+    pub fn iter(&self) -> impl Iterator<Item = &RawField<'a>> {
         self.fields.iter()
     }
 
-    /// Return all matching [RawField] by the field's key.
+    /// Return all matching [RawField] by the field's key. Field names are
+    /// case-insensitive, so `package` will match a `Package` field.
     pub fn field<'field>(
         &'field self,
         field_name: &'field str,
-    ) -> impl Iterator<Item = &'field RawField> {
-        self.fields.iter().filter(move |f| f.key == field_name)
+    ) -> impl Iterator<Item = &'field RawField<'a>> {
+        self.fields
+            .iter()
+            .filter(move |f| f.key.eq_ignore_ascii_case(field_name))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::control::RawParagraph;
+    use crate::control::{Error, ParseOptions, RawField, RawParagraph};
+    use std::borrow::Cow;
 
     macro_rules! check_paragraph_parse {
         ($name:ident, $paragraph:expr, |$para:ident| $block:tt ) => {
@@ -280,6 +524,192 @@ Key:Name: Value?
             assert_eq!("Name: Value?", p.field("Key").next().unwrap().value);
         }
     );
+
+    check_paragraph_parse!(
+        check_parse_borrowed,
+        "\
+Key: Value
+Multi:
+ Line
+ Value
+Empty:
+",
+        |p| {
+            let key = p.field("Key").next().unwrap();
+            assert!(matches!(key.key, Cow::Borrowed(_)));
+            assert!(matches!(key.value, Cow::Borrowed("Value")));
+
+            let multi = p.field("Multi").next().unwrap();
+            assert!(matches!(multi.key, Cow::Borrowed(_)));
+            assert!(matches!(multi.value, Cow::Owned(_)));
+            assert_eq!("\nLine\nValue", multi.value);
+
+            let empty = p.field("Empty").next().unwrap();
+            assert_eq!("", empty.value);
+        }
+    );
+
+    const STRICT_FIELD_TYPES: ParseOptions = ParseOptions {
+        strict_field_types: true,
+        reject_duplicate_fields: false,
+    };
+
+    const REJECT_DUPLICATE_FIELDS: ParseOptions = ParseOptions {
+        strict_field_types: false,
+        reject_duplicate_fields: true,
+    };
+
+    #[test]
+    fn check_duplicate_fields() {
+        let paragraph = "\
+Package: hello
+Version: 1.0
+Architecture: amd64
+Version: 2.0
+";
+        assert_eq!(
+            2,
+            RawParagraph::parse(paragraph)
+                .unwrap()
+                .field("Version")
+                .count()
+        );
+        assert_eq!(
+            Err(Error::DuplicateField("Version".to_owned())),
+            RawParagraph::parse_with_options(paragraph, &REJECT_DUPLICATE_FIELDS)
+        );
+        assert_eq!(
+            Err(Error::DuplicateField("version".to_owned())),
+            RawParagraph::parse_with_options(
+                "Version: 1.0\nversion: 1.0\n",
+                &REJECT_DUPLICATE_FIELDS
+            )
+        );
+        assert!(
+            RawParagraph::parse_with_options(
+                "Package: hello\nVersion: 1.0\n",
+                &REJECT_DUPLICATE_FIELDS
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn check_strict_multiline_simple_field() {
+        let paragraph = "\
+Package:
+ hello
+Version: 1.0
+";
+        assert_eq!(
+            "\nhello",
+            RawParagraph::parse(paragraph)
+                .unwrap()
+                .field("Package")
+                .next()
+                .unwrap()
+                .value
+        );
+        assert_eq!(
+            Err(Error::MultilineSimpleField("Package".to_owned())),
+            RawParagraph::parse_with_options(paragraph, &STRICT_FIELD_TYPES)
+        );
+    }
+
+    #[test]
+    fn check_strict_folded_field() {
+        let p = RawParagraph::parse_with_options(
+            "\
+Package: hello
+Depends: libc6,
+ foo
+Description: hi
+ there
+",
+            &STRICT_FIELD_TYPES,
+        )
+        .unwrap();
+        assert_eq!("hello", p.field("Package").next().unwrap().value);
+        assert_eq!("libc6,\nfoo", p.field("Depends").next().unwrap().value);
+    }
+
+    #[test]
+    fn check_span() {
+        let input = "\
+Source: hello
+# a comment
+Binary: hello,
+ hello-dbg
+Empty:
+Version: 2.10-3";
+        let p = RawParagraph::parse(input).unwrap();
+        assert_eq!(
+            vec![
+                "Source: hello",
+                "Binary: hello,\n hello-dbg",
+                "Empty:",
+                "Version: 2.10-3"
+            ],
+            p.iter()
+                .map(|field| &input[field.span().unwrap()])
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(0..13), p.fields[0].span());
+        assert_eq!(Some(26..51), p.fields[1].span());
+    }
+
+    #[test]
+    fn check_eq_ignores_span() {
+        let p = RawParagraph::parse("Source: hello\nVersion: 2.10-3\n").unwrap();
+        let q = RawParagraph::parse("Version: 2.10-3\n").unwrap();
+        assert_ne!(p.fields[1].span(), q.fields[0].span());
+        assert_eq!(p.fields[1], q.fields[0]);
+
+        let version = RawField::new("Version", "2.10-3");
+        assert_eq!(None, version.span());
+        assert_eq!(version, q.fields[0]);
+        assert_ne!(RawField::new("Version", "2.10-4"), q.fields[0]);
+    }
+
+    #[test]
+    fn check_display() {
+        let input = "\
+Key: Value
+Multi:
+ Line
+ .
+ Value
+Folded: a,
+    b
+Empty:
+";
+        assert_eq!(input, RawParagraph::parse(input).unwrap().to_string());
+    }
+
+    #[test]
+    fn check_key_casing() {
+        let input = "package: hello\nVERSION: 2.10-3\n";
+        let p = RawParagraph::parse(input).unwrap();
+
+        let package = p.field("Package").next().unwrap();
+        assert_eq!("package", package.key);
+        assert_eq!("hello", package.value);
+        assert_eq!("2.10-3", p.field("version").next().unwrap().value);
+
+        assert_eq!(input, p.to_string());
+        assert_eq!(input, p.into_owned().to_string());
+    }
+
+    #[test]
+    fn check_into_owned() {
+        let p = {
+            let input = "Key: Value\n".to_owned();
+            RawParagraph::parse(&input).unwrap().into_owned()
+        };
+        let key = p.field("Key").next().unwrap();
+        assert!(matches!(key.key, Cow::Owned(_)));
+        assert_eq!("Value", key.value);
+    }
 }
 
 // vim: foldmethod=marker
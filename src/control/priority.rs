@@ -29,7 +29,11 @@ use std::str::FromStr;
 /// Most Debian packages will have a priority of optional. Priority levels
 /// other than optional are only used for packages that should be included by
 /// default in a standard installation of Debian.
-#[derive(Copy, Clone, Debug, PartialEq)]
+///
+/// [Priority] values are ordered by how essential they are, so
+/// [Priority::Required] is the greatest, and the deprecated
+/// [Priority::Extra] is the least.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Priority {
     /// Packages which are necessary for the proper functioning of the system
     /// (usually, this means that dpkg functionality depends on these
@@ -88,6 +92,41 @@ pub enum PriorityParseError {
     Unknown,
 }
 
+impl Priority {
+    /// Parse a [Priority] on a best-effort basis, returning `None` rather
+    /// than an error if the value is empty or unknown. This is useful when
+    /// the priority is nice to have (such as when gathering archive
+    /// statistics) and a bad value shouldn't stop processing.
+    pub fn parse_optional(s: &str) -> Option<Priority> {
+        s.parse().ok()
+    }
+}
+
+impl Priority {
+    /// Rank of the [Priority], higher being more essential.
+    fn rank(&self) -> u8 {
+        match self {
+            Priority::Required => 4,
+            Priority::Important => 3,
+            Priority::Standard => 2,
+            Priority::Optional => 1,
+            Priority::Extra => 0,
+        }
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
 impl FromStr for Priority {
     type Err = PriorityParseError;
 
@@ -167,6 +206,46 @@ mod tests {
 
     check_fails!(fails_empty, "");
     check_fails!(fails_bogus, "bogus");
+
+    macro_rules! check_optional {
+        ( $name:ident, $priority_str:expr, $expected:expr ) => {
+            #[test]
+            fn $name() {
+                assert_eq!($expected, Priority::parse_optional($priority_str));
+            }
+        };
+    }
+
+    check_optional!(optional_optional, "optional", Some(Priority::Optional));
+    check_optional!(optional_extra, "extra", Some(Priority::Extra));
+    check_optional!(optional_empty, "", None);
+    check_optional!(optional_bogus, "bogus", None);
+
+    #[test]
+    fn ordering() {
+        assert!(Priority::Required > Priority::Optional);
+        assert!(Priority::Optional > Priority::Extra);
+
+        let mut priorities = vec![
+            Priority::Optional,
+            Priority::Extra,
+            Priority::Required,
+            Priority::Standard,
+            Priority::Important,
+        ];
+        priorities.sort();
+        priorities.reverse();
+        assert_eq!(
+            vec![
+                Priority::Required,
+                Priority::Important,
+                Priority::Standard,
+                Priority::Optional,
+                Priority::Extra,
+            ],
+            priorities
+        );
+    }
 }
 
 // vim: foldmethod=marker
@@ -22,7 +22,7 @@
 use ::serde::{Deserialize, Serialize};
 
 #[cfg(feature = "serde")]
-use crate::control::de;
+use crate::control::{de, ser};
 
 use super::Action;
 
@@ -49,6 +49,10 @@ pub enum CommandError {
     /// Underlying [de::Error] serde decoding error.
     #[cfg(feature = "serde")]
     De(de::Error),
+
+    /// Underlying [ser::Error] serde encoding error.
+    #[cfg(feature = "serde")]
+    Ser(ser::Error),
 }
 
 #[cfg(feature = "serde")]
@@ -58,8 +62,23 @@ impl From<de::Error> for CommandError {
     }
 }
 
+#[cfg(feature = "serde")]
+impl From<ser::Error> for CommandError {
+    fn from(see: ser::Error) -> Self {
+        Self::Ser(see)
+    }
+}
+
 crate::errors::error_enum!(CommandError);
 
+#[cfg(feature = "serde")]
+impl Command {
+    /// Encode this [Command] as a queued `.commands` file.
+    pub fn to_string(&self) -> Result<String, CommandError> {
+        Ok(ser::to_string(self)?)
+    }
+}
+
 #[cfg(all(test, feature = "serde"))]
 mod tests {
     use super::*;
@@ -89,6 +108,36 @@ Commands:
             command.commands.as_slice()
         );
     }
+
+    #[test]
+    fn command_round_trip() {
+        let command = Command {
+            uploader: "Paul Tagliamonte <paultag@debian.org>".to_owned(),
+            commands: vec![
+                Action::Rm("--searchdirs foo.deb".to_owned()),
+                Action::Reschedule("foo.changes 2-day".to_owned()),
+                Action::Cancel("bar.changes".to_owned()),
+            ],
+        };
+
+        let encoded = command.to_string().unwrap();
+        assert_eq!(
+            "\
+Uploader: Paul Tagliamonte <paultag@debian.org>
+Commands:
+ rm --searchdirs foo.deb
+ reschedule foo.changes 2-day
+ cancel bar.changes
+",
+            encoded
+        );
+        assert_eq!(command, de::from_str::<Command>(&encoded).unwrap());
+    }
+
+    #[test]
+    fn command_missing_commands() {
+        assert!(de::from_str::<Command>("Uploader: Some One <some@example.com>\n").is_err());
+    }
 }
 
 // vim: foldmethod=marker
@@ -21,6 +21,10 @@
 //! Rust types to handle Deserialization of
 //! queued `.commands`
 //! files used by Debian Developers to control the archive.
+//!
+//! The upload queue only understands the `rm`, `cancel` and `reschedule`
+//! commands, each of which is an [Action]. Anything else is kept as an
+//! [Action::Unknown] rather than rejected.
 
 mod action;
 mod command;
@@ -37,10 +37,25 @@ pub enum Error {
 
     /// Bad type
     BadType,
+
+    /// A value started with whitespace on the same line as the key, which
+    /// can't be represented without the whitespace being lost or mistaken
+    /// for the separator. Values made up only of whitespace aren't an
+    /// error; they're written as an empty field.
+    BadFieldValue,
+
+    /// Underlying transport issue generally caused by some i/o boundary.
+    Io(std::io::Error),
 }
 
 impl std::error::Error for Error {}
 
+impl From<std::io::Error> for Error {
+    fn from(ioe: std::io::Error) -> Self {
+        Self::Io(ioe)
+    }
+}
+
 impl std::fmt::Display for Error {
     fn fmt(&self, w: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(w, "{self:?}")
@@ -57,6 +72,12 @@ impl serde::ser::Error for Error {
 }
 
 /// Encode the provided value to a Debian RFC 2822 style stanza.
+///
+/// A `None` value is written out as a Field with an empty value, which
+/// is indistinguishable from `Some("")`. When read back using
+/// [crate::control::de], that Field will become `Some("")`. Use
+/// `#[serde(skip_serializing_if = "Option::is_none")]` if the Field should
+/// be omitted entirely.
 pub fn to_string<T>(value: &T) -> Result<String, Error>
 where
     T: Serialize,
@@ -66,6 +87,48 @@ where
     Ok(serializer.output())
 }
 
+/// Types which have a conventional order for their fields, such as the
+/// order `dpkg` writes out a `.changes` or `.dsc` file in.
+pub trait FieldOrder {
+    /// Field names, in the order that they should be written.
+    const FIELD_ORDER: &'static [&'static str];
+}
+
+/// Encode the provided value to a Debian RFC 2822 style stanza, the same
+/// way [to_string] does, but with Fields written in the order given by
+/// [FieldOrder::FIELD_ORDER] rather than the order they were declared in
+/// the Rust type.
+///
+/// Any Field not listed is written after all the known Fields, in the
+/// order it was serialized in.
+pub fn to_string_policy_ordered<T>(value: &T) -> Result<String, Error>
+where
+    T: Serialize + FieldOrder,
+{
+    let encoded = to_string(value)?;
+
+    let mut fields: Vec<(&str, Vec<&str>)> = vec![];
+    for line in encoded.lines() {
+        match fields.last_mut() {
+            Some((_, lines)) if line.starts_with([' ', '\t']) => lines.push(line),
+            _ => fields.push((line.split(':').next().unwrap_or(line), vec![line])),
+        }
+    }
+
+    fields.sort_by_key(|(key, _)| {
+        T::FIELD_ORDER
+            .iter()
+            .position(|field| field.eq_ignore_ascii_case(key))
+            .unwrap_or(T::FIELD_ORDER.len())
+    });
+
+    Ok(fields
+        .into_iter()
+        .flat_map(|(_, lines)| lines)
+        .map(|line| format!("{line}\n"))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,6 +173,48 @@ Ello: Foo
         );
     }
 
+    #[test]
+    fn test_option_none() {
+        assert_eq!(
+            to_string(&TestControlFile {
+                package: "foo".to_owned(),
+                foo: "bar".to_owned(),
+                true_false: false,
+                a_number: 0,
+                ello: None,
+            })
+            .unwrap(),
+            "\
+Package: foo
+Foo: bar
+True-False: no
+X-A-Number: 0
+Ello:
+"
+        );
+    }
+
+    #[test]
+    fn test_option_some_empty() {
+        assert_eq!(
+            to_string(&TestControlFile {
+                package: "foo".to_owned(),
+                foo: "bar".to_owned(),
+                true_false: false,
+                a_number: 0,
+                ello: Some("".to_owned()),
+            })
+            .unwrap(),
+            "\
+Package: foo
+Foo: bar
+True-False: no
+X-A-Number: 0
+Ello:
+"
+        );
+    }
+
     #[derive(Clone, Debug, PartialEq, Serialize)]
     struct TestControl {
         #[serde(rename = "Foo")]
@@ -132,6 +237,61 @@ Foo:
         );
     }
 
+    #[test]
+    fn test_string_leading_whitespace() {
+        for foo in [" foo", "\tfoo", "  \nfoo"] {
+            assert!(matches!(
+                to_string(&TestControl {
+                    foo: foo.to_owned()
+                }),
+                Err(Error::BadFieldValue)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_string_whitespace_only() {
+        for foo in ["  ", "\t", " \n "] {
+            assert_eq!(
+                to_string(&TestControl {
+                    foo: foo.to_owned()
+                })
+                .unwrap(),
+                "Foo:\n"
+            );
+        }
+    }
+
+    #[test]
+    fn test_string_indented_continuation() {
+        assert_eq!(
+            to_string(&TestControl {
+                foo: "foo\n  bar".to_owned()
+            })
+            .unwrap(),
+            "\
+Foo: foo
+   bar
+"
+        );
+    }
+
+    #[test]
+    fn test_string_leading_whitespace_multiline() {
+        assert_eq!(
+            to_string(&TestControl {
+                foo: "
+  foo"
+                .to_owned()
+            })
+            .unwrap(),
+            "\
+Foo:
+   foo
+"
+        );
+    }
+
     #[test]
     fn test_string_empty_lines() {
         assert_eq!(
@@ -175,9 +335,222 @@ Multiline:
  1.2
  1.3
  1.4
+",
+        );
+    }
+
+    #[test]
+    fn test_policy_ordered() {
+        #[derive(Clone, Debug, PartialEq, Serialize)]
+        struct Ordered {
+            #[serde(rename = "Multiline")]
+            multiline: Vec<String>,
+
+            #[serde(rename = "Unknown")]
+            unknown: String,
+
+            #[serde(rename = "Package")]
+            package: String,
+
+            #[serde(rename = "Foo")]
+            foo: String,
+        }
+
+        impl FieldOrder for Ordered {
+            const FIELD_ORDER: &'static [&'static str] = &["Package", "Foo", "Multiline"];
+        }
+
+        assert_eq!(
+            to_string_policy_ordered(&Ordered {
+                multiline: vec!["a".to_owned(), "b".to_owned()],
+                unknown: "?".to_owned(),
+                package: "foo".to_owned(),
+                foo: "bar".to_owned(),
+            })
+            .unwrap(),
+            "\
+Package: foo
+Foo: bar
+Multiline:
+ a
+ b
+Unknown: ?
+",
+        );
+    }
+
+    #[test]
+    fn test_flatten() {
+        #[derive(Clone, Debug, PartialEq, Serialize)]
+        struct Inner {
+            #[serde(rename = "Foo")]
+            foo: String,
+
+            #[serde(rename = "Multiline")]
+            multiline: Vec<String>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, Serialize)]
+        struct Outer {
+            #[serde(rename = "Package")]
+            package: String,
+
+            #[serde(flatten)]
+            inner: Inner,
+
+            #[serde(rename = "Ello")]
+            ello: Option<String>,
+        }
+
+        assert_eq!(
+            to_string(&Outer {
+                package: "foo".to_owned(),
+                inner: Inner {
+                    foo: "bar".to_owned(),
+                    multiline: vec!["a".to_owned(), "b".to_owned()],
+                },
+                ello: None,
+            })
+            .unwrap(),
+            "\
+Package: foo
+Foo: bar
+Multiline:
+ a
+ b
+Ello:
 ",
         );
     }
 }
 
+#[cfg(feature = "tokio")]
+mod _tokio {
+    #![cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+
+    use super::*;
+    use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+    /// Encode the provided value to a Debian RFC 2822 style stanza, the same
+    /// way [to_string] does, and write it to a [tokio::io::AsyncWrite].
+    ///
+    /// ```no_run
+    /// use deb::control::{ser, package::Changes};
+    ///
+    /// async fn write_changes(changes: &Changes) {
+    ///     let mut output: Vec<u8> = vec![];
+    ///     ser::to_writer_async(&mut output, changes).await.unwrap();
+    /// }
+    /// ```
+    pub async fn to_writer_async<WriteT, T>(output: &mut WriteT, value: &T) -> Result<(), Error>
+    where
+        WriteT: AsyncWrite,
+        WriteT: Unpin,
+        T: Serialize,
+    {
+        output.write_all(to_string(value)?.as_bytes()).await?;
+        output.flush().await?;
+        Ok(())
+    }
+
+    /// Encode each of the provided values to a Debian RFC 2822 style
+    /// stanza, and write them to a [tokio::io::AsyncWrite], separated by
+    /// a blank line. This is the counterpart of
+    /// [crate::control::de::from_reader_async_iter].
+    ///
+    /// ```no_run
+    /// use deb::control::{ser, archive::Package};
+    ///
+    /// async fn write_packages(packages: &[Package]) {
+    ///     let mut output: Vec<u8> = vec![];
+    ///     ser::to_writer_async_all(&mut output, packages).await.unwrap();
+    /// }
+    /// ```
+    pub async fn to_writer_async_all<WriteT, T, IterT>(
+        output: &mut WriteT,
+        values: IterT,
+    ) -> Result<(), Error>
+    where
+        WriteT: AsyncWrite,
+        WriteT: Unpin,
+        IterT: IntoIterator<Item = T>,
+        T: Serialize,
+    {
+        for (idx, value) in values.into_iter().enumerate() {
+            if idx != 0 {
+                output.write_all(b"\n").await?;
+            }
+            output.write_all(to_string(&value)?.as_bytes()).await?;
+        }
+        output.flush().await?;
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::control::de;
+        use serde::Deserialize;
+        use std::io::Cursor;
+        use tokio::io::BufReader;
+
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct TestControl {
+            #[serde(rename = "Hello")]
+            hello: String,
+        }
+
+        #[tokio::test]
+        async fn test_to_writer_async() {
+            let mut output = Cursor::new(vec![]);
+            to_writer_async(
+                &mut output,
+                &TestControl {
+                    hello: "World".to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+
+            let output = output.into_inner();
+            assert_eq!("Hello: World\n", String::from_utf8_lossy(&output));
+
+            let mut reader = BufReader::new(Cursor::new(output));
+            let test: TestControl = de::from_reader_async(&mut reader).await.unwrap();
+            assert_eq!(test.hello, "World");
+        }
+
+        #[tokio::test]
+        async fn test_to_writer_async_all() {
+            let values = ["World", "Paul", "You", "Me"]
+                .into_iter()
+                .map(|hello| TestControl {
+                    hello: hello.to_owned(),
+                })
+                .collect::<Vec<_>>();
+
+            let mut output = Cursor::new(vec![]);
+            to_writer_async_all(&mut output, &values).await.unwrap();
+
+            let output = output.into_inner();
+            assert_eq!(
+                "Hello: World\n\nHello: Paul\n\nHello: You\n\nHello: Me\n",
+                String::from_utf8_lossy(&output)
+            );
+
+            let mut reader = BufReader::new(Cursor::new(output));
+            let mut iter = de::from_reader_async_iter(&mut reader);
+            let mut decoded = vec![];
+            while let Some(rv) = iter.next().await {
+                let rv: TestControl = rv.unwrap();
+                decoded.push(rv);
+            }
+            assert_eq!(values, decoded);
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+pub use _tokio::{to_writer_async, to_writer_async_all};
+
 // vim: foldmethod=marker
@@ -19,11 +19,18 @@
 // THE SOFTWARE. }}}
 
 use super::Error;
+use crate::control::as_sequence::AS_SEQUENCE;
 use serde::{Serialize, ser};
 
 #[derive(Clone, Default)]
 pub(super) struct Serializer {
     pub(super) output: String,
+
+    /// When set, sequence elements are written space separated on a single
+    /// line rather than one per continuation line, along with how many
+    /// elements have been written so far. This is used by
+    /// [crate::control::AsSequence].
+    inline_seq: Option<usize>,
 }
 
 impl Serializer {
@@ -100,6 +107,14 @@ impl ser::Serializer for &mut Serializer {
     }
 
     fn serialize_str(self, v: &str) -> Result<()> {
+        if v.trim_end().starts_with([' ', '\t']) {
+            // leading whitespace on the same line as the key can't be told
+            // apart from the separator. Continuation lines are fine, since
+            // only the single space we indent them with is stripped when
+            // parsing, and a whitespace-only value is trimmed to an empty
+            // field like any other trailing whitespace.
+            return Err(Error::BadFieldValue);
+        }
         self.output += format!("{}\n", v.trim_end())
             .replace("\n\n", "\n.\n")
             .replace("\n", "\n ")
@@ -139,10 +154,16 @@ impl ser::Serializer for &mut Serializer {
         self.serialize_str(variant)
     }
 
-    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
     {
+        if name == AS_SEQUENCE {
+            let inline_seq = self.inline_seq.replace(0);
+            let ret = value.serialize(&mut *self);
+            self.inline_seq = inline_seq;
+            return ret;
+        }
         value.serialize(self)
     }
 
@@ -214,7 +235,14 @@ impl ser::SerializeSeq for &mut Serializer {
     where
         T: ?Sized + Serialize,
     {
-        self.output += "\n ";
+        match &mut self.inline_seq {
+            Some(0) => self.inline_seq = Some(1),
+            Some(n) => {
+                *n += 1;
+                self.output += " ";
+            }
+            None => self.output += "\n ",
+        }
         value.serialize(&mut **self)
     }
 
@@ -332,8 +360,8 @@ impl ser::SerializeMap for &mut Serializer {
     where
         T: ?Sized + Serialize,
     {
-        self.output += " ";
         value.serialize(&mut **self)?;
+        self.output += "\n";
         Ok(())
     }
 
@@ -0,0 +1,340 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::{State, Status};
+use crate::{control::package::BinaryControl, version::Version};
+
+/// Single package's entry in the `dpkg` status database.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusEntry {
+    /// Name of the package.
+    pub package: String,
+
+    /// Binary control entry from the package's `DEBIAN/control` file.
+    ///
+    /// Packages which were removed (and maybe purged) can still have an
+    /// entry in the [State::NotInstalled] state, such as
+    /// `purge ok not-installed`, without the `Version`, `Maintainer` or
+    /// `Description` a [BinaryControl] needs. This is `None` for those
+    /// entries. Entries in any other state must have a valid
+    /// [BinaryControl].
+    pub control: Option<BinaryControl>,
+
+    /// What should happen to this package, and what state it's in.
+    pub status: Status,
+
+    /// Version of the package the configuration files were last set up
+    /// for, if the package has been configured.
+    pub config_version: Option<Version>,
+
+    /// Configuration files owned by the package, one per line, each
+    /// with the path and the MD5 digest of the file as shipped (and
+    /// optionally followed by `obsolete` or `remove-on-upgrade`).
+    pub conffiles: Option<String>,
+}
+
+impl StatusEntry {
+    /// Return true if the package is fully installed and configured on the
+    /// system, no matter what action has been selected for it (so a package
+    /// on [super::Want::Hold] is still installed).
+    pub fn is_installed(&self) -> bool {
+        self.status.state == State::Installed
+    }
+}
+
+#[cfg(feature = "serde")]
+mod _serde {
+    #![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+    use super::*;
+    use crate::control::de;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
+    use std::{
+        collections::BTreeMap,
+        io::{BufReader, Read},
+    };
+
+    /// Fields of a [StatusEntry] which every entry has, no matter the
+    /// [State].
+    #[derive(Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct StatusFields {
+        package: String,
+        status: Status,
+        #[serde(rename = "Config-Version")]
+        config_version: Option<Version>,
+        conffiles: Option<String>,
+    }
+
+    /// Borrowed [StatusEntry], used to encode it. The `Package` is written
+    /// by the [BinaryControl] if there is one.
+    #[derive(Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct StatusEntryRef<'a> {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        package: Option<&'a str>,
+        #[serde(flatten)]
+        control: &'a Option<BinaryControl>,
+        status: &'a Status,
+        #[serde(rename = "Config-Version")]
+        config_version: &'a Option<Version>,
+        conffiles: &'a Option<String>,
+    }
+
+    impl Serialize for StatusEntry {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            StatusEntryRef {
+                package: match self.control {
+                    Some(_) => None,
+                    None => Some(&self.package),
+                },
+                control: &self.control,
+                status: &self.status,
+                config_version: &self.config_version,
+                conffiles: &self.conffiles,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for StatusEntry {
+        fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let fields = BTreeMap::<String, String>::deserialize(d)?;
+            let fields = || {
+                fields
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str()))
+            };
+
+            let StatusFields {
+                package,
+                status,
+                config_version,
+                conffiles,
+            } = de::from_fields(fields()).map_err(D::Error::custom)?;
+
+            let control = match de::from_fields::<BinaryControl, _>(fields()) {
+                Ok(control) => Some(control),
+                Err(_) if status.state == State::NotInstalled => None,
+                Err(err) => return Err(D::Error::custom(err)),
+            };
+
+            Ok(StatusEntry {
+                package,
+                control,
+                status,
+                config_version,
+                conffiles,
+            })
+        }
+    }
+
+    /// Stream every [StatusEntry] from the provided `dpkg` status database,
+    /// such as `/var/lib/dpkg/status`.
+    ///
+    /// ```no_run
+    /// use deb::control::status;
+    /// use std::{fs::File, io::BufReader};
+    ///
+    /// let mut input = BufReader::new(File::open("/var/lib/dpkg/status").unwrap());
+    /// for entry in status::entries(&mut input) {
+    ///     let entry = entry.unwrap();
+    ///     println!("{} {}", entry.package, entry.status);
+    /// }
+    /// ```
+    pub fn entries<ReadT>(
+        input: &mut BufReader<ReadT>,
+    ) -> impl Iterator<Item = Result<StatusEntry, de::Error>> + use<'_, ReadT>
+    where
+        ReadT: Read,
+    {
+        de::from_reader_iter(input)
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use _serde::entries;
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    mod serde {
+        use crate::{
+            architecture,
+            control::{
+                RawParagraph, de, ser,
+                status::{self, State, StatusEntry, Want},
+            },
+        };
+        use std::io::{BufReader, Cursor};
+
+        const STATUS: &str = "\
+Package: hello
+Status: install ok installed
+Priority: optional
+Section: devel
+Installed-Size: 280
+Maintainer: Santiago Vila <sanvila@debian.org>
+Architecture: amd64
+Version: 2.10-3
+Depends: libc6 (>= 2.34)
+Description: example package based on GNU hello
+ The GNU hello program produces a familiar, friendly greeting.  It
+ allows non-programmers to use a classic computer science tool which
+ would otherwise be unavailable to them.
+Homepage: https://www.gnu.org/software/hello/
+
+Package: openssh-server
+Status: deinstall ok config-files
+Priority: optional
+Section: net
+Installed-Size: 1826
+Maintainer: Debian OpenSSH Maintainers <debian-ssh@lists.debian.org>
+Architecture: amd64
+Source: openssh
+Version: 1:9.2p1-2+deb12u3
+Config-Version: 1:9.2p1-2+deb12u3
+Conffiles:
+ /etc/default/ssh 500e3cf069fe9a7b9936108eb9d9c035
+ /etc/init.d/ssh 3649a6fe8c18ad1d5245fd91737de507
+Description: secure shell (SSH) server, for secure access from remote machines
+
+Package: libfoo1
+Status: purge ok not-installed
+Priority: optional
+Section: libs
+
+Package: telnet
+Status: deinstall ok not-installed
+Architecture: amd64
+";
+
+        #[test]
+        fn parse_entries() {
+            let mut reader = BufReader::new(Cursor::new(STATUS));
+            let entries = status::entries(&mut reader)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(4, entries.len());
+
+            let hello = &entries[0];
+            assert_eq!("hello", hello.package);
+            let control = hello.control.as_ref().unwrap();
+            assert_eq!("hello", control.package);
+            assert_eq!(Some(architecture::AMD64), control.architecture);
+            assert_eq!("install ok installed", hello.status.to_string());
+            assert_eq!(State::Installed, hello.status.state);
+            assert!(hello.is_installed());
+            assert_eq!(None, hello.config_version);
+            assert_eq!(None, hello.conffiles);
+
+            let sshd = &entries[1];
+            assert_eq!("openssh-server", sshd.package);
+            assert_eq!(
+                "openssh",
+                sshd.control.as_ref().unwrap().source.as_ref().unwrap().name
+            );
+            assert_eq!(Want::Deinstall, sshd.status.want);
+            assert_eq!(State::ConfigFiles, sshd.status.state);
+            assert!(!sshd.is_installed());
+            assert_eq!(
+                Some("1:9.2p1-2+deb12u3".parse().unwrap()),
+                sshd.config_version
+            );
+            assert_eq!(
+                2,
+                sshd.conffiles
+                    .as_ref()
+                    .unwrap()
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .count()
+            );
+        }
+
+        #[test]
+        fn parse_not_installed() {
+            let mut reader = BufReader::new(Cursor::new(STATUS));
+            let entries = status::entries(&mut reader)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            let libfoo = &entries[2];
+            assert_eq!("libfoo1", libfoo.package);
+            assert_eq!(None, libfoo.control);
+            assert_eq!(Want::Purge, libfoo.status.want);
+            assert_eq!(State::NotInstalled, libfoo.status.state);
+            assert!(!libfoo.is_installed());
+
+            let telnet = &entries[3];
+            assert_eq!("telnet", telnet.package);
+            assert_eq!(None, telnet.control);
+            assert_eq!(Want::Deinstall, telnet.status.want);
+        }
+
+        #[test]
+        fn parse_installed_missing_version() {
+            // only not-installed entries may leave out the control fields.
+            let mut reader = BufReader::new(Cursor::new(STATUS.replace("Version: 2.10-3\n", "")));
+            let entries = status::entries(&mut reader).collect::<Vec<_>>();
+            assert!(entries[0].is_err());
+            assert!(entries[1].is_ok());
+        }
+
+        #[test]
+        fn round_trip() {
+            let mut reader = BufReader::new(Cursor::new(STATUS));
+            for entry in status::entries(&mut reader) {
+                let entry = entry.unwrap();
+                // absent fields are written out empty, and some (such as
+                // `Package-Type`) fail to parse when empty, so drop them.
+                let encoded = ser::to_string(&entry).unwrap();
+                let mut paragraph = RawParagraph::parse(&encoded).unwrap();
+                paragraph
+                    .fields
+                    .retain(|field| !field.value.trim().is_empty());
+                let encoded = paragraph.to_string();
+                assert_eq!(entry, de::from_str::<StatusEntry>(&encoded).unwrap());
+            }
+        }
+
+        #[test]
+        fn held_is_installed() {
+            let mut reader = BufReader::new(Cursor::new(
+                STATUS.replace("Status: install ok installed", "Status: hold ok installed"),
+            ));
+            let hello = status::entries(&mut reader).next().unwrap().unwrap();
+            assert_eq!(Want::Hold, hello.status.want);
+            assert!(hello.is_installed());
+        }
+
+        #[test]
+        fn parse_bad_status() {
+            let mut reader = BufReader::new(Cursor::new(
+                STATUS.replace("Status: install ok installed", "Status: install ok"),
+            ));
+            let entries = status::entries(&mut reader).collect::<Vec<_>>();
+            assert!(entries[0].is_err());
+            assert!(entries[1].is_ok());
+        }
+    }
+}
+
+// vim: foldmethod=marker
@@ -0,0 +1,40 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+//! Rust types to handle Deserialization of the `dpkg` status database,
+//! found at `/var/lib/dpkg/status`.
+//!
+//! The database is a series of paragraphs, one for each package `dpkg`
+//! knows about, each of which looks like the package's
+//! [crate::control::package::BinaryControl] with a few extra fields
+//! tracking the state of the package on the system. The most important
+//! of these is the [Status] field. Each paragraph is decoded into a
+//! [StatusEntry], and the whole database can be read with [entries].
+
+mod entry;
+#[allow(clippy::module_inception)]
+mod status;
+
+pub use entry::StatusEntry;
+#[cfg(feature = "serde")]
+pub use entry::entries;
+pub use status::{Flag, State, Status, StatusParseError, Want};
+
+// vim: foldmethod=marker
@@ -0,0 +1,299 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use crate::control::def_serde_traits_for;
+use std::str::FromStr;
+
+/// Action the user (or a frontend such as `apt`) has selected for a
+/// package, the first word of a [Status].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Want {
+    /// No action has been selected for the package.
+    Unknown,
+
+    /// The package is selected for installation.
+    Install,
+
+    /// The package is held at its current version, and won't be changed
+    /// by `dpkg` unless forced.
+    Hold,
+
+    /// The package is selected for removal, keeping its configuration
+    /// files.
+    Deinstall,
+
+    /// The package is selected to be purged, removing everything
+    /// including its configuration files.
+    Purge,
+}
+
+/// Error flag of a package, the second word of a [Status].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Flag {
+    /// Nothing is wrong with the package.
+    Ok,
+
+    /// The package is broken, and needs to be reinstalled before it can
+    /// be removed.
+    Reinstreq,
+}
+
+/// State of the package on the system, the third word of a [Status].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum State {
+    /// The package isn't installed on the system.
+    NotInstalled,
+
+    /// Only the package's configuration files are left on the system.
+    ConfigFiles,
+
+    /// Installation of the package was started, but not completed.
+    HalfInstalled,
+
+    /// The package was unpacked, but not configured.
+    Unpacked,
+
+    /// The package was unpacked, and configuration was started, but not
+    /// completed.
+    HalfConfigured,
+
+    /// The package is waiting on trigger processing by another package.
+    TriggersAwaited,
+
+    /// The package has been triggered, but the triggers haven't been
+    /// processed yet.
+    TriggersPending,
+
+    /// The package is unpacked and configured.
+    Installed,
+}
+
+/// Value of the `Status` field of a [super::StatusEntry], such as
+/// `install ok installed`, made up of three words: the action the user
+/// wants for the package, a flag for any error, and the package's current
+/// state on the system.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Status {
+    /// Action selected for the package, such as [Want::Install] or
+    /// [Want::Hold].
+    pub want: Want,
+
+    /// Error flag for the package, [Flag::Ok] unless something went wrong.
+    pub flag: Flag,
+
+    /// Current state of the package, such as [State::Installed] or
+    /// [State::ConfigFiles].
+    pub state: State,
+}
+
+def_serde_traits_for!(Status);
+
+/// Error conditions which may be encountered when parsing a [Status].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StatusParseError {
+    /// The [Status] wasn't made up of exactly three words.
+    Malformed,
+
+    /// The first word of the [Status] isn't a known [Want].
+    UnknownWant,
+
+    /// The second word of the [Status] isn't a known [Flag].
+    UnknownFlag,
+
+    /// The third word of the [Status] isn't a known [State].
+    UnknownState,
+}
+crate::errors::error_enum!(StatusParseError);
+
+impl Want {
+    /// Return the [Want] as it's written in the `Status` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Want::Unknown => "unknown",
+            Want::Install => "install",
+            Want::Hold => "hold",
+            Want::Deinstall => "deinstall",
+            Want::Purge => "purge",
+        }
+    }
+}
+
+impl FromStr for Want {
+    type Err = StatusParseError;
+
+    fn from_str(want: &str) -> Result<Self, Self::Err> {
+        Ok(match want {
+            "unknown" => Want::Unknown,
+            "install" => Want::Install,
+            "hold" => Want::Hold,
+            "deinstall" => Want::Deinstall,
+            "purge" => Want::Purge,
+            _ => return Err(StatusParseError::UnknownWant),
+        })
+    }
+}
+
+impl Flag {
+    /// Return the [Flag] as it's written in the `Status` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Flag::Ok => "ok",
+            Flag::Reinstreq => "reinstreq",
+        }
+    }
+}
+
+impl FromStr for Flag {
+    type Err = StatusParseError;
+
+    fn from_str(flag: &str) -> Result<Self, Self::Err> {
+        Ok(match flag {
+            "ok" => Flag::Ok,
+            "reinstreq" => Flag::Reinstreq,
+            _ => return Err(StatusParseError::UnknownFlag),
+        })
+    }
+}
+
+impl State {
+    /// Return the [State] as it's written in the `Status` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            State::NotInstalled => "not-installed",
+            State::ConfigFiles => "config-files",
+            State::HalfInstalled => "half-installed",
+            State::Unpacked => "unpacked",
+            State::HalfConfigured => "half-configured",
+            State::TriggersAwaited => "triggers-awaited",
+            State::TriggersPending => "triggers-pending",
+            State::Installed => "installed",
+        }
+    }
+}
+
+impl FromStr for State {
+    type Err = StatusParseError;
+
+    fn from_str(state: &str) -> Result<Self, Self::Err> {
+        Ok(match state {
+            "not-installed" => State::NotInstalled,
+            "config-files" => State::ConfigFiles,
+            "half-installed" => State::HalfInstalled,
+            "unpacked" => State::Unpacked,
+            "half-configured" => State::HalfConfigured,
+            "triggers-awaited" => State::TriggersAwaited,
+            "triggers-pending" => State::TriggersPending,
+            "installed" => State::Installed,
+            _ => return Err(StatusParseError::UnknownState),
+        })
+    }
+}
+
+impl FromStr for Status {
+    type Err = StatusParseError;
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        let Ok::<[&str; 3], _>([want, flag, state]) =
+            status.split_whitespace().collect::<Vec<_>>().try_into()
+        else {
+            return Err(StatusParseError::Malformed);
+        };
+
+        Ok(Status {
+            want: want.parse()?,
+            flag: flag.parse()?,
+            state: state.parse()?,
+        })
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{} {} {}",
+            self.want.as_str(),
+            self.flag.as_str(),
+            self.state.as_str()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::control::{def_failing_parse_test, def_parse_test};
+
+    def_parse_test!(
+        parse_installed,
+        Status,
+        "install ok installed",
+        Status {
+            want: Want::Install,
+            flag: Flag::Ok,
+            state: State::Installed,
+        }
+    );
+    def_parse_test!(
+        parse_config_files,
+        Status,
+        "deinstall ok config-files",
+        Status {
+            want: Want::Deinstall,
+            flag: Flag::Ok,
+            state: State::ConfigFiles,
+        }
+    );
+    def_parse_test!(
+        parse_hold,
+        Status,
+        "hold ok installed",
+        Status {
+            want: Want::Hold,
+            flag: Flag::Ok,
+            state: State::Installed,
+        }
+    );
+    def_parse_test!(
+        parse_reinstreq,
+        Status,
+        "install reinstreq half-installed",
+        Status {
+            want: Want::Install,
+            flag: Flag::Reinstreq,
+            state: State::HalfInstalled,
+        }
+    );
+
+    def_failing_parse_test!(parse_empty, Status, "");
+    def_failing_parse_test!(parse_short, Status, "install ok");
+    def_failing_parse_test!(parse_long, Status, "install ok installed now");
+    def_failing_parse_test!(parse_bad_want, Status, "upgrade ok installed");
+    def_failing_parse_test!(parse_bad_flag, Status, "install bad installed");
+    def_failing_parse_test!(parse_bad_state, Status, "install ok removed");
+
+    #[test]
+    fn round_trip() {
+        let status: Status = "hold ok installed".parse().unwrap();
+        assert_eq!("hold ok installed", status.to_string());
+    }
+}
+
+// vim: foldmethod=marker
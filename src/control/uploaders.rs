@@ -0,0 +1,207 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::{Maintainer, MaintainerParseError, def_serde_traits_for};
+use std::{ops::Deref, str::FromStr};
+
+/// List of co-maintainers of a package, as found in the `Uploaders` field.
+///
+/// Entries are separated by commas, but unlike [super::CommaDelimitedStrings],
+/// commas inside a quoted name or a parenthesized comment don't split an
+/// entry, so names such as `"Vila, Santiago"` are kept intact. Empty
+/// entries (such as from a trailing comma) are skipped.
+///
+/// ```
+/// use deb::control::Uploaders;
+///
+/// let uploaders: Uploaders = "\"Vila, Santiago\" <sanvila@debian.org>, Matthias Klose <doko@debian.org>,"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(2, uploaders.len());
+/// assert_eq!(Some("Vila, Santiago"), uploaders[0].name.as_deref());
+/// assert_eq!("doko@debian.org", uploaders[1].email);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Uploaders(pub Vec<Maintainer>);
+
+def_serde_traits_for!(Uploaders);
+
+/// Split a comma separated list of RFC 822 style addresses, ignoring any
+/// commas within a quoted string, a comment or an `<address>`. Entries are
+/// trimmed, and empty entries are dropped.
+pub(crate) fn split_addresses(input: &str) -> Vec<&str> {
+    let mut ret = vec![];
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut depth = 0usize;
+    let mut in_address = false;
+
+    for (idx, ch) in input.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' if quoted || depth > 0 => escaped = true,
+            '"' if depth == 0 && !in_address => quoted = !quoted,
+            '(' if !quoted && !in_address => depth += 1,
+            ')' if !quoted && !in_address => depth = depth.saturating_sub(1),
+            '<' if !quoted && depth == 0 => in_address = true,
+            '>' if !quoted && depth == 0 => in_address = false,
+            ',' if !quoted && depth == 0 && !in_address => {
+                ret.push(input[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    ret.push(input[start..].trim());
+    ret.retain(|entry| !entry.is_empty());
+    ret
+}
+
+impl Deref for Uploaders {
+    type Target = [Maintainer];
+    fn deref(&self) -> &[Maintainer] {
+        &self.0
+    }
+}
+
+impl FromStr for Uploaders {
+    type Err = MaintainerParseError;
+
+    fn from_str(uploaders: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            split_addresses(uploaders)
+                .into_iter()
+                .map(|uploader| uploader.parse())
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl std::fmt::Display for Uploaders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|uploader| uploader.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! check_split {
+        ($name:ident, $uploaders:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let expected: &[&str] = &$expected;
+                assert_eq!(expected, split_addresses($uploaders));
+            }
+        };
+    }
+
+    check_split!(split_empty, "", []);
+    check_split!(
+        split_simple,
+        "Santiago Vila <sanvila@debian.org>, Matthias Klose <doko@debian.org>",
+        [
+            "Santiago Vila <sanvila@debian.org>",
+            "Matthias Klose <doko@debian.org>"
+        ]
+    );
+    check_split!(
+        split_quoted_comma,
+        "\"Vila, Santiago\" <sanvila@debian.org>, Matthias Klose <doko@debian.org>",
+        [
+            "\"Vila, Santiago\" <sanvila@debian.org>",
+            "Matthias Klose <doko@debian.org>"
+        ]
+    );
+    check_split!(
+        split_escaped_quote,
+        r#""Santiago \", Vila" <sanvila@debian.org>, doko@debian.org"#,
+        [
+            r#""Santiago \", Vila" <sanvila@debian.org>"#,
+            "doko@debian.org"
+        ]
+    );
+    check_split!(
+        split_comment_comma,
+        "sanvila@debian.org (Vila, Santiago), doko@debian.org",
+        ["sanvila@debian.org (Vila, Santiago)", "doko@debian.org"]
+    );
+    check_split!(
+        split_trailing_comma,
+        "Santiago Vila <sanvila@debian.org>,\n Matthias Klose <doko@debian.org>,",
+        [
+            "Santiago Vila <sanvila@debian.org>",
+            "Matthias Klose <doko@debian.org>"
+        ]
+    );
+
+    #[test]
+    fn parse_quoted_comma() {
+        let uploaders: Uploaders = "\"Vila, Santiago\" <sanvila@debian.org>, doko@debian.org"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            vec![
+                Maintainer {
+                    name: Some("Vila, Santiago".to_owned()),
+                    email: "sanvila@debian.org".to_owned(),
+                },
+                Maintainer {
+                    name: None,
+                    email: "doko@debian.org".to_owned(),
+                },
+            ],
+            uploaders.0
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            Err(MaintainerParseError::InvalidEmail),
+            "Santiago Vila <sanvila@debian.org>, Matthias Klose".parse::<Uploaders>()
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let uploaders = "\"Vila, Santiago\" <sanvila@debian.org>, Matthias Klose <doko@debian.org>";
+        assert_eq!(
+            uploaders,
+            uploaders.parse::<Uploaders>().unwrap().to_string()
+        );
+    }
+}
+
+// vim: foldmethod=marker
@@ -0,0 +1,321 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+//! Parsing of the `debian/watch` file, used by `uscan` to check for new
+//! upstream releases of a package.
+//!
+//! ```
+//! use deb::control::watch::Watch;
+//!
+//! let watch: Watch = "\
+//! version=4
+//! opts=pgpsigurlmangle=s/$/.sig/ \\
+//!   https://ftp.gnu.org/gnu/hello/ hello-(\\d[\\d.]*)\\.tar\\.gz
+//! ".parse().unwrap();
+//!
+//! assert_eq!(4, watch.version);
+//! assert_eq!("https://ftp.gnu.org/gnu/hello/", watch.entries[0].url_pattern);
+//! ```
+
+use std::str::FromStr;
+
+/// Error conditions which may be encountered when parsing a [Watch] file.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchParseError {
+    /// The file didn't start with a `version=N` line.
+    MissingVersion,
+
+    /// The `version=` line didn't contain a valid number.
+    InvalidVersion(String),
+
+    /// An `opts="...` prefix was opened but never closed.
+    UnterminatedOpts,
+
+    /// A watch line had options, but no URL to go with them.
+    MissingUrl,
+
+    /// A watch line had more fields than the URL, matching pattern,
+    /// version and script.
+    TooManyFields(String),
+}
+crate::errors::error_enum!(WatchParseError);
+
+/// Parsed `debian/watch` file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Watch {
+    /// Format version of the file, from the leading `version=N` line.
+    pub version: u32,
+
+    /// Each watch line in the file.
+    pub entries: Vec<WatchEntry>,
+}
+
+/// Single watch line of a [Watch] file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchEntry {
+    /// URL to check. If no [WatchEntry::matching_pattern] is given, the
+    /// last path component of this URL is the pattern to match files
+    /// against.
+    pub url_pattern: String,
+
+    /// Regular expression matched against the links found at the
+    /// [WatchEntry::url_pattern], if it was given as its own field.
+    pub matching_pattern: Option<String>,
+
+    /// Version policy, such as `debian`, `same` or `ignore`, if set.
+    pub version_policy: Option<String>,
+
+    /// Script to run after a new upstream release has been downloaded,
+    /// such as `uupdate`, if set.
+    pub script: Option<String>,
+
+    /// Options given with the `opts=` prefix, as a list of names and
+    /// their value, if there was one.
+    pub opts: Vec<(String, Option<String>)>,
+}
+
+/// Join lines ending with a backslash with the line following them,
+/// dropping comments and blank lines.
+fn logical_lines(input: &str) -> Vec<String> {
+    let mut ret = vec![];
+    let mut current: Option<String> = None;
+
+    for line in input.lines() {
+        let line = match current {
+            Some(_) => line.trim(),
+            None => {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                line
+            }
+        };
+
+        let (line, continued) = match line.strip_suffix('\\') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+
+        let joined = match current.take() {
+            Some(prefix) => prefix + line,
+            None => line.to_owned(),
+        };
+
+        if continued {
+            current = Some(joined);
+        } else {
+            ret.push(joined);
+        }
+    }
+
+    if let Some(line) = current {
+        ret.push(line);
+    }
+
+    ret
+}
+
+fn parse_opts(opts: &str) -> Vec<(String, Option<String>)> {
+    opts.split(',')
+        .map(|opt| opt.trim())
+        .filter(|opt| !opt.is_empty())
+        .map(|opt| match opt.split_once('=') {
+            Some((name, value)) => (name.trim().to_owned(), Some(value.trim().to_owned())),
+            None => (opt.to_owned(), None),
+        })
+        .collect()
+}
+
+impl FromStr for WatchEntry {
+    type Err = WatchParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+
+        let (opts, rest) = match line.strip_prefix("opts=") {
+            Some(rest) => match rest.strip_prefix('"') {
+                Some(rest) => {
+                    let (opts, rest) = rest
+                        .split_once('"')
+                        .ok_or(WatchParseError::UnterminatedOpts)?;
+                    (parse_opts(opts), rest)
+                }
+                None => match rest.split_once(char::is_whitespace) {
+                    Some((opts, rest)) => (parse_opts(opts), rest),
+                    None => (parse_opts(rest), ""),
+                },
+            },
+            None => (vec![], line),
+        };
+
+        let mut fields = rest.split_whitespace().map(|field| field.to_owned());
+        let url_pattern = fields.next().ok_or(WatchParseError::MissingUrl)?;
+        let matching_pattern = fields.next();
+        let version_policy = fields.next();
+        let script = fields.next();
+
+        if fields.next().is_some() {
+            return Err(WatchParseError::TooManyFields(line.to_owned()));
+        }
+
+        Ok(WatchEntry {
+            url_pattern,
+            matching_pattern,
+            version_policy,
+            script,
+            opts,
+        })
+    }
+}
+
+impl FromStr for Watch {
+    type Err = WatchParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let lines = logical_lines(input);
+        let mut lines = lines.iter();
+
+        let version = lines
+            .next()
+            .and_then(|line| line.strip_prefix("version"))
+            .and_then(|line| line.trim_start().strip_prefix('='))
+            .ok_or(WatchParseError::MissingVersion)?
+            .trim();
+        let version = version
+            .parse()
+            .map_err(|_| WatchParseError::InvalidVersion(version.to_owned()))?;
+
+        Ok(Watch {
+            version,
+            entries: lines
+                .map(|line| line.parse())
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GNU_WATCH: &str = "\
+# Compulsory line, this is a version 4 file
+version=4
+
+# GNU hello, checked along with its detached signature.
+opts=\"pgpsigurlmangle=s/$/.sig/, uversionmangle=s/-rc/~rc/\" \\
+  https://ftp.gnu.org/gnu/hello/ \\
+  hello-(\\d[\\d.]*)\\.tar\\.(?:gz|xz) debian uupdate
+
+opts=pgpmode=none https://ftp.gnu.org/gnu/hello/hello-(\\d[\\d.]*)\\.tar\\.gz
+";
+
+    #[test]
+    fn parse_gnu_watch() {
+        let watch: Watch = GNU_WATCH.parse().unwrap();
+        assert_eq!(4, watch.version);
+        assert_eq!(2, watch.entries.len());
+
+        let entry = &watch.entries[0];
+        assert_eq!("https://ftp.gnu.org/gnu/hello/", entry.url_pattern);
+        assert_eq!(
+            Some("hello-(\\d[\\d.]*)\\.tar\\.(?:gz|xz)"),
+            entry.matching_pattern.as_deref()
+        );
+        assert_eq!(Some("debian"), entry.version_policy.as_deref());
+        assert_eq!(Some("uupdate"), entry.script.as_deref());
+        assert_eq!(
+            vec![
+                ("pgpsigurlmangle".to_owned(), Some("s/$/.sig/".to_owned())),
+                ("uversionmangle".to_owned(), Some("s/-rc/~rc/".to_owned())),
+            ],
+            entry.opts
+        );
+
+        let entry = &watch.entries[1];
+        assert_eq!(
+            "https://ftp.gnu.org/gnu/hello/hello-(\\d[\\d.]*)\\.tar\\.gz",
+            entry.url_pattern
+        );
+        assert_eq!(None, entry.matching_pattern);
+        assert_eq!(None, entry.version_policy);
+        assert_eq!(None, entry.script);
+        assert_eq!(
+            vec![("pgpmode".to_owned(), Some("none".to_owned()))],
+            entry.opts
+        );
+    }
+
+    #[test]
+    fn parse_no_opts() {
+        let watch: Watch = "version=3\nhttp://example.com/ foo-(.*)\\.tar\\.gz\n"
+            .parse()
+            .unwrap();
+        assert_eq!(3, watch.version);
+        assert!(watch.entries[0].opts.is_empty());
+        assert_eq!(
+            Some("foo-(.*)\\.tar\\.gz"),
+            watch.entries[0].matching_pattern.as_deref()
+        );
+    }
+
+    #[test]
+    fn parse_flag_opts() {
+        let entry: WatchEntry =
+            "opts=repacksuffix=+dfsg,dversionmangle=auto,decompress http://example.com/"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            vec![
+                ("repacksuffix".to_owned(), Some("+dfsg".to_owned())),
+                ("dversionmangle".to_owned(), Some("auto".to_owned())),
+                ("decompress".to_owned(), None),
+            ],
+            entry.opts
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err(WatchParseError::MissingVersion),
+            "http://example.com/ foo-(.*)".parse::<Watch>()
+        );
+        assert_eq!(
+            Err(WatchParseError::InvalidVersion("four".to_owned())),
+            "version=four\n".parse::<Watch>()
+        );
+        assert_eq!(
+            Err(WatchParseError::UnterminatedOpts),
+            "version=4\nopts=\"foo=bar http://example.com/\n".parse::<Watch>()
+        );
+        assert_eq!(
+            Err(WatchParseError::MissingUrl),
+            "version=4\nopts=foo=bar\n".parse::<Watch>()
+        );
+        assert!(matches!(
+            "version=4\nhttp://example.com/ a b c d\n".parse::<Watch>(),
+            Err(WatchParseError::TooManyFields(_))
+        ));
+    }
+}
+
+// vim: foldmethod=marker
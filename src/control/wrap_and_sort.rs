@@ -0,0 +1,394 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::{RawField, RawParagraph, uploaders::split_addresses};
+
+/// Fields which contain a [crate::dependency::Dependency], and have their
+/// contents sorted by [RawParagraph::canonicalize].
+const RELATIONSHIP_FIELDS: &[&str] = &[
+    "Pre-Depends",
+    "Depends",
+    "Recommends",
+    "Suggests",
+    "Enhances",
+    "Breaks",
+    "Conflicts",
+    "Replaces",
+    "Provides",
+    "Built-Using",
+    "Static-Built-Using",
+    "Build-Depends",
+    "Build-Depends-Arch",
+    "Build-Depends-Indep",
+    "Build-Conflicts",
+    "Build-Conflicts-Arch",
+    "Build-Conflicts-Indep",
+];
+
+/// Comma separated fields which are wrapped by [RawParagraph::canonicalize],
+/// but where the order is meaningful, so are left unsorted.
+const UNSORTED_LIST_FIELDS: &[&str] = &["Uploaders", "Testsuite-Triggers"];
+
+/// Conventional order of fields in a `debian/control` stanza, covering both
+/// the source stanza and binary stanzas.
+const CONTROL_FIELD_ORDER: &[&str] = &[
+    "Source",
+    "Package",
+    "Architecture",
+    "Multi-Arch",
+    "Section",
+    "Priority",
+    "Essential",
+    "Maintainer",
+    "Uploaders",
+    "Build-Depends",
+    "Build-Depends-Arch",
+    "Build-Depends-Indep",
+    "Build-Conflicts",
+    "Build-Conflicts-Arch",
+    "Build-Conflicts-Indep",
+    "Standards-Version",
+    "Homepage",
+    "Vcs-Browser",
+    "Vcs-Arch",
+    "Vcs-Bzr",
+    "Vcs-Cvs",
+    "Vcs-Darcs",
+    "Vcs-Git",
+    "Vcs-Hg",
+    "Vcs-Mtn",
+    "Vcs-Svn",
+    "Testsuite",
+    "Testsuite-Triggers",
+    "Rules-Requires-Root",
+    "Pre-Depends",
+    "Depends",
+    "Recommends",
+    "Suggests",
+    "Enhances",
+    "Breaks",
+    "Conflicts",
+    "Replaces",
+    "Provides",
+    "Built-Using",
+    "Static-Built-Using",
+    "Description",
+];
+
+/// Options controlling how [RawParagraph::canonicalize] lays out a
+/// paragraph, loosely following the flags to `wrap-and-sort`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WrapOpts {
+    /// Put each entry of a list field on its own line, even if they'd all
+    /// fit on one line (`wrap-and-sort --wrap-always`).
+    pub wrap_always: bool,
+
+    /// Indent continuation lines with a single space, rather than lining
+    /// them up with the first entry (`wrap-and-sort --short-indent`).
+    pub short_indent: bool,
+
+    /// Add a comma after the last entry of a list field which has been
+    /// wrapped over multiple lines (`wrap-and-sort --trailing-comma`).
+    pub trailing_comma: bool,
+
+    /// Longest line, including the field name, a list field may be kept
+    /// on before it's wrapped.
+    pub max_line_length: usize,
+
+    /// Order to write fields in. Fields not listed here are written after
+    /// all the listed fields, in the order they were in originally.
+    pub field_order: &'static [&'static str],
+}
+
+impl Default for WrapOpts {
+    fn default() -> Self {
+        Self {
+            wrap_always: false,
+            short_indent: false,
+            trailing_comma: false,
+            max_line_length: 79,
+            field_order: CONTROL_FIELD_ORDER,
+        }
+    }
+}
+
+fn field_is(fields: &[&str], key: &str) -> bool {
+    fields.iter().any(|field| field.eq_ignore_ascii_case(key))
+}
+
+/// Split a comma separated list, collapsing runs of whitespace (including
+/// newlines) down to a single space. `Uploaders` is split on commas outside
+/// of quoted names, so `"Vila, Santiago"` stays a single entry.
+fn split_list(key: &str, value: &str) -> Vec<String> {
+    let entries = if key.eq_ignore_ascii_case("Uploaders") {
+        split_addresses(value)
+    } else {
+        value.split(',').collect()
+    };
+    entries
+        .into_iter()
+        .map(|entry| entry.split_ascii_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+/// Sort the same way `wrap-and-sort` does -- package names first, and
+/// anything else (like `${misc:Depends}`) after.
+fn sort_list(entries: &mut Vec<String>) {
+    entries.sort_by(|a, b| {
+        let special = |entry: &str| !entry.starts_with(|c: char| c.is_ascii_alphanumeric());
+        (special(a), a).cmp(&(special(b), b))
+    });
+    entries.dedup();
+}
+
+fn wrap_list(key: &str, entries: &[String], opts: &WrapOpts) -> String {
+    let one_line = entries.join(", ");
+    if !opts.wrap_always && key.len() + 2 + one_line.len() <= opts.max_line_length {
+        return one_line;
+    }
+
+    // every continuation line is written with a single leading space, so
+    // only the alignment beyond that is part of the value.
+    let indent = if opts.short_indent {
+        String::new()
+    } else {
+        " ".repeat(key.len() + 1)
+    };
+
+    let mut ret = String::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        if idx == 0 && !opts.short_indent {
+            ret.push_str(entry);
+        } else {
+            ret.push('\n');
+            if idx != 0 {
+                ret.push_str(&indent);
+            }
+            ret.push_str(entry);
+        }
+        if idx + 1 != entries.len() || opts.trailing_comma {
+            ret.push(',');
+        }
+    }
+    ret
+}
+
+impl<'a> RawParagraph<'a> {
+    /// Return a copy of this [RawParagraph] in a canonical form, the same
+    /// way `wrap-and-sort` would tidy up a `debian/control` stanza. Only
+    /// the following changes are made:
+    ///
+    /// - Relationship fields (such as `Depends` or `Build-Depends`) have
+    ///   whitespace within each entry collapsed, empty and duplicate entries
+    ///   removed, and are sorted, with substitution variables last.
+    /// - `Uploaders` and `Testsuite-Triggers` are cleaned up the same way,
+    ///   but are left in their original order.
+    /// - Those list fields are written on one line if they fit in
+    ///   [WrapOpts::max_line_length], and one entry per line otherwise.
+    /// - Fields are reordered by [WrapOpts::field_order].
+    ///
+    /// Every other field's value, such as `Description`, is left as-is.
+    /// Field names are matched without regard to case, and aren't changed.
+    pub fn canonicalize(&self, opts: WrapOpts) -> RawParagraph<'a> {
+        let mut fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                let sorted = field_is(RELATIONSHIP_FIELDS, &field.key);
+                if !sorted && !field_is(UNSORTED_LIST_FIELDS, &field.key) {
+                    return field.clone();
+                }
+                let mut entries = split_list(&field.key, &field.value);
+                if sorted {
+                    sort_list(&mut entries);
+                }
+                let value = wrap_list(&field.key, &entries, &opts);
+                RawField::new(field.key.clone(), value)
+            })
+            .collect::<Vec<_>>();
+
+        fields.sort_by_key(|field| {
+            opts.field_order
+                .iter()
+                .position(|key| key.eq_ignore_ascii_case(&field.key))
+                .unwrap_or(opts.field_order.len())
+        });
+
+        RawParagraph { fields }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MESSY: &str = "\
+Build-Depends: texinfo,debhelper-compat (= 13),
+    help2man  ,
+ ${misc:Depends}, texinfo
+Source: hello
+Maintainer: Santiago Vila <sanvila@debian.org>
+Uploaders: Zed <z@example.com>,
+ Anna <a@example.com>
+X-Custom: keep
+ me
+Standards-Version: 4.6.2
+Section: devel
+Priority: optional
+";
+
+    #[test]
+    fn canonicalize_messy() {
+        let p = RawParagraph::parse(MESSY).unwrap();
+        assert_eq!(
+            "\
+Source: hello
+Section: devel
+Priority: optional
+Maintainer: Santiago Vila <sanvila@debian.org>
+Uploaders: Zed <z@example.com>, Anna <a@example.com>
+Build-Depends: debhelper-compat (= 13), help2man, texinfo, ${misc:Depends}
+Standards-Version: 4.6.2
+X-Custom: keep
+ me
+",
+            p.canonicalize(WrapOpts::default()).to_string()
+        );
+    }
+
+    #[test]
+    fn canonicalize_quoted_uploaders() {
+        let p = RawParagraph::parse(
+            "Source: hello\nUploaders: \"Vila, Santiago\" <sanvila@debian.org>,\n Anna <a@example.com>,\n",
+        )
+        .unwrap();
+        assert_eq!(
+            "\"Vila, Santiago\" <sanvila@debian.org>, Anna <a@example.com>",
+            p.canonicalize(WrapOpts::default())
+                .field("Uploaders")
+                .next()
+                .unwrap()
+                .value
+        );
+    }
+
+    #[test]
+    fn canonicalize_span() {
+        let p = RawParagraph::parse(MESSY).unwrap();
+        let canonical = p.canonicalize(WrapOpts::default());
+
+        // rewritten fields no longer match the input.
+        assert_eq!(
+            None,
+            canonical.field("Build-Depends").next().unwrap().span()
+        );
+
+        // untouched fields still point at the input, wherever they moved.
+        let source = canonical.field("Source").next().unwrap();
+        assert_eq!("Source: hello", &MESSY[source.span().unwrap()]);
+    }
+
+    #[test]
+    fn canonicalize_wrap_always() {
+        let p = RawParagraph::parse(MESSY).unwrap();
+        let canonical = p
+            .canonicalize(WrapOpts {
+                wrap_always: true,
+                trailing_comma: true,
+                ..Default::default()
+            })
+            .to_string();
+        assert!(canonical.contains(
+            "\
+Build-Depends: debhelper-compat (= 13),
+               help2man,
+               texinfo,
+               ${misc:Depends},
+"
+        ));
+
+        let canonical = p
+            .canonicalize(WrapOpts {
+                wrap_always: true,
+                short_indent: true,
+                ..Default::default()
+            })
+            .to_string();
+        assert!(canonical.contains(
+            "\
+Build-Depends:
+ debhelper-compat (= 13),
+ help2man,
+ texinfo,
+ ${misc:Depends}
+"
+        ));
+    }
+
+    #[test]
+    fn canonicalize_long_line() {
+        let p = RawParagraph::parse(
+            "\
+Package: hello
+Depends: libaaaaaaaaaaaaaaaaaaaa, libbbbbbbbbbbbbbbbbbbbbbbbbb, libcccccccccccccccccc
+",
+        )
+        .unwrap();
+        assert_eq!(
+            "\
+Package: hello
+Depends: libaaaaaaaaaaaaaaaaaaaa,
+         libbbbbbbbbbbbbbbbbbbbbbbbbb,
+         libcccccccccccccccccc
+",
+            p.canonicalize(WrapOpts::default()).to_string()
+        );
+    }
+
+    #[test]
+    fn canonicalize_is_stable() {
+        for opts in [
+            WrapOpts::default(),
+            WrapOpts {
+                wrap_always: true,
+                ..Default::default()
+            },
+            WrapOpts {
+                wrap_always: true,
+                short_indent: true,
+                trailing_comma: true,
+                ..Default::default()
+            },
+        ] {
+            let once = RawParagraph::parse(MESSY)
+                .unwrap()
+                .canonicalize(opts)
+                .to_string();
+            let twice = RawParagraph::parse(&once)
+                .unwrap()
+                .canonicalize(opts)
+                .to_string();
+            assert_eq!(once, twice);
+        }
+    }
+}
+
+// vim: foldmethod=marker
@@ -34,6 +34,9 @@ use std::str::FromStr;
 /// Each [Dependency] is comprised of a set of [Relation]s, which must all
 /// be satisfied in order for the [Dependency] to be satisfied. Each [Relation]
 /// is effectively an `AND` operation.
+///
+/// Parsing an empty (or whitespace-only) string will return a [Dependency]
+/// with no [Relation]s, which is always satisfied.
 #[derive(Clone, Debug, PartialEq, Default)]
 pub struct Dependency {
     /// Set of [Relation] values which must *all* be satisfied in order for
@@ -57,6 +60,11 @@ pub enum Error {
     /// can not be correctly parsed.
     InvalidPackage,
 
+    /// [crate::dependency::Package] name isn't a valid Debian package name,
+    /// which must be at least two characters long, contain only lowercase
+    /// letters, digits, `+`, `-` and `.`, and start with a letter or digit.
+    InvalidPackageName,
+
     /// [crate::version::Version] String is malformed in some way that can not
     /// be correctly parsed.
     InvalidVersion(version::Error),
@@ -93,6 +101,17 @@ pub enum Error {
     TooManyArches,
 }
 
+/// A single relation from a dependency field which couldn't be parsed by
+/// [Dependency::parse_lenient], such as one containing a `${substvar}`.
+#[derive(Clone, Debug)]
+pub struct SkippedRelation {
+    /// Text of the relation, with leading and trailing whitespace removed.
+    pub relation: String,
+
+    /// Error encountered when parsing the relation.
+    pub error: Error,
+}
+
 impl From<architecture::Error> for Error {
     fn from(err: architecture::Error) -> Self {
         Error::InvalidArch(err)
@@ -117,6 +136,171 @@ impl From<PestError<Rule>> for Error {
     }
 }
 
+impl Dependency {
+    /// Return true if this [Dependency] has no [Relation]s at all, such as
+    /// when parsed from an empty string. An empty [Dependency] is always
+    /// satisfied.
+    ///
+    /// ```
+    /// use deb::dependency::Dependency;
+    ///
+    /// let dep: Dependency = "".parse().unwrap();
+    /// assert!(dep.is_empty());
+    /// assert_eq!(0, dep.len());
+    ///
+    /// let dep: Dependency = "foo | bar, baz".parse().unwrap();
+    /// assert!(!dep.is_empty());
+    /// assert_eq!(2, dep.len());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.relations.is_empty()
+    }
+
+    /// Return the number of [Relation]s in this [Dependency]. A set of
+    /// alternatives (such as `foo | bar`) counts as a single [Relation].
+    pub fn len(&self) -> usize {
+        self.relations.len()
+    }
+
+    /// Return every [Relation] in this [Dependency] which lists a
+    /// [crate::dependency::Package] named `package_name`, either on its own
+    /// or as one of a set of alternatives.
+    ///
+    /// ```
+    /// use deb::dependency::Dependency;
+    ///
+    /// let dep: Dependency = "libssl-dev | libressl-dev, zlib1g-dev".parse().unwrap();
+    /// let relations = dep.iter_alternatives_for("libssl-dev").collect::<Vec<_>>();
+    /// assert_eq!(1, relations.len());
+    /// assert_eq!("libssl-dev | libressl-dev", relations[0].to_string());
+    /// ```
+    pub fn iter_alternatives_for<'a>(
+        &'a self,
+        package_name: &'a str,
+    ) -> impl Iterator<Item = &'a Relation> {
+        self.relations.iter().filter(move |relation| {
+            relation
+                .packages
+                .iter()
+                .any(|package| package.name == package_name)
+        })
+    }
+
+    /// Parse a dependency field one relation at a time, rather than all at
+    /// once like [FromStr] does. Relations which can't be parsed (such as
+    /// `${misc:Depends}` in a `debian/control` file) are returned as a
+    /// [SkippedRelation] rather than failing the whole field.
+    ///
+    /// ```
+    /// use deb::dependency::Dependency;
+    ///
+    /// let (dep, skipped) = Dependency::parse_lenient("foo, ${misc:Depends}, bar");
+    /// assert_eq!("foo, bar", dep.to_string());
+    /// assert_eq!(1, skipped.len());
+    /// assert_eq!("${misc:Depends}", skipped[0].relation);
+    /// ```
+    pub fn parse_lenient(input: &str) -> (Dependency, Vec<SkippedRelation>) {
+        let mut ret = Dependency { relations: vec![] };
+        let mut skipped = vec![];
+
+        for relation in input.split(',') {
+            let relation = relation.trim_ascii();
+            if relation.is_empty() {
+                continue;
+            }
+            match relation.parse::<Dependency>() {
+                Ok(dep) => ret.relations.extend(dep.relations),
+                Err(error) => skipped.push(SkippedRelation {
+                    relation: relation.to_owned(),
+                    error,
+                }),
+            }
+        }
+
+        (ret, skipped)
+    }
+
+    /// Render this [Dependency] as a human readable tree, for debugging. Each
+    /// [Relation] is numbered, with each alternative on its own line, and
+    /// any constraints listed under the [crate::dependency::Package] they
+    /// apply to. Unlike [std::fmt::Display], this is not a format which can
+    /// be parsed back, and may change.
+    ///
+    /// ```
+    /// use deb::dependency::Dependency;
+    ///
+    /// let dep: Dependency = "a, b (>= 1.0) | c [amd64]".parse().unwrap();
+    /// assert_eq!(
+    ///     "\
+    /// 1. a
+    /// 2. b
+    ///      version: >= 1.0
+    ///    | c
+    ///      architectures: amd64
+    /// ",
+    ///     dep.explain()
+    /// );
+    /// ```
+    pub fn explain(&self) -> String {
+        let mut ret = String::new();
+        for (idx, relation) in self.relations.iter().enumerate() {
+            let number = format!("{}. ", idx + 1);
+            let indent = " ".repeat(number.len());
+
+            for (alt, package) in relation.packages.iter().enumerate() {
+                if alt == 0 {
+                    ret.push_str(&number);
+                } else {
+                    ret.push_str(&indent);
+                    ret.push_str("| ");
+                }
+                ret.push_str(&package.name);
+                ret.push('\n');
+
+                let mut annotate = |what: &str, value: String| {
+                    ret.push_str(&format!("{indent}  {what}: {value}\n"));
+                };
+                if let Some(arch) = &package.arch {
+                    annotate("arch", arch.to_string());
+                }
+                if let Some(version_constraint) = &package.version_constraint {
+                    annotate("version", version_constraint.to_string());
+                }
+                if let Some(arch_constraints) = &package.arch_constraints {
+                    annotate("architectures", arch_constraints.to_string());
+                }
+                if let Some(bprf) = &package.build_profile_restriction_formula {
+                    annotate("build profiles", bprf.to_string());
+                }
+            }
+        }
+        ret
+    }
+
+    /// Combine the [Relation]s of several [Dependency] values into a single
+    /// [Dependency], such as when `Pre-Depends` and `Depends` both need to
+    /// be satisfied. Relations which are exactly the same as one already
+    /// seen are dropped; order is otherwise preserved.
+    ///
+    /// ```
+    /// use deb::dependency::Dependency;
+    ///
+    /// let pre_depends: Dependency = "libc6 (>= 2.36)".parse().unwrap();
+    /// let depends: Dependency = "libc6 (>= 2.36), zlib1g".parse().unwrap();
+    /// let merged = Dependency::merged(&[&pre_depends, &depends]);
+    /// assert_eq!("libc6 (>= 2.36), zlib1g", merged.to_string());
+    /// ```
+    pub fn merged(deps: &[&Dependency]) -> Dependency {
+        let mut relations: Vec<Relation> = vec![];
+        for relation in deps.iter().flat_map(|dep| dep.relations.iter()) {
+            if !relations.contains(relation) {
+                relations.push(relation.clone());
+            }
+        }
+        Dependency { relations }
+    }
+}
+
 impl std::fmt::Display for Dependency {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(
@@ -153,6 +337,10 @@ impl FromStr for Dependency {
     type Err = Error;
 
     fn from_str(v: &str) -> Result<Self, Error> {
+        if v.trim_ascii().is_empty() {
+            // Fields which are present but blank have no dependencies.
+            return Ok(Dependency { relations: vec![] });
+        }
         let Some(token) = DependencyParser::parse(Rule::dependency, v)?.next() else {
             // No dependencies, empty.
             return Ok(Dependency { relations: vec![] });
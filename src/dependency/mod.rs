@@ -76,14 +76,16 @@ mod pest;
 mod relation;
 mod tests;
 mod version;
+mod version_range;
 
 pub use architecture::{ArchConstraint, ArchConstraints, ArchConstraintsValidationError};
 pub use build_profile::{
     BuildProfileConstraint, BuildProfileConstraints, BuildProfileRestrictionFormula,
 };
-pub use dependency::{Dependency, Error};
+pub use dependency::{Dependency, Error, SkippedRelation};
 pub use package::Package;
 pub use relation::Relation;
 pub use version::{VersionConstraint, VersionOperator};
+pub use version_range::{VersionBound, VersionRange};
 
 // vim: foldmethod=marker
@@ -19,9 +19,13 @@
 // THE SOFTWARE. }}}
 
 use super::{
-    ArchConstraints, BuildProfileRestrictionFormula, Error, VersionConstraint, pest::Rule,
+    ArchConstraints, BuildProfileRestrictionFormula, Dependency, Error, VersionConstraint,
+    VersionOperator, pest::Rule,
+};
+use crate::{
+    architecture::{self, Architecture},
+    version::Version,
 };
-use crate::architecture::Architecture;
 use pest::iterators::Pair;
 
 /// A [Package] is the lowest level of [crate::dependency::Dependency]
@@ -36,6 +40,15 @@ use pest::iterators::Pair;
 /// In general, you're unlikely to be parsing these directly, instead
 /// you're likely going to see a [Package] by parsing a
 /// [crate::dependency::Dependency].
+///
+/// # Default
+///
+/// The [Default] value has an empty `name`, and no constraints. This is
+/// handy as a template (`Package { name: "foo".to_owned(),
+/// ..Default::default() }`), but on its own is *not* a valid [Package],
+/// since Debian package names may not be empty. Calling `to_string()` on
+/// the [Default] value will return an empty string. Prefer [Package::new],
+/// which checks the `name` is a valid Debian package name.
 #[derive(Clone, Debug, PartialEq, Default)]
 pub struct Package {
     /// Name of the package which may satisfy this particular Dependency
@@ -89,6 +102,143 @@ pub struct Package {
     pub build_profile_restriction_formula: Option<BuildProfileRestrictionFormula>,
 }
 
+/// Check `name` against the Debian Policy rules for package names -- at
+/// least two characters long, made up of only lowercase letters, digits,
+/// `+`, `-` and `.`, and starting with a letter or digit.
+fn is_valid_package_name(name: &str) -> bool {
+    name.len() >= 2
+        && name.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "+-.".contains(c))
+}
+
+impl Package {
+    /// Create a new [Package] with the provided `name` and no constraints.
+    /// The `with_*` methods can then be used to add constraints, for
+    /// instance when building a [crate::dependency::Dependency] in code
+    /// rather than by parsing it.
+    ///
+    /// This will return an [Error::InvalidPackageName] if `name` isn't a
+    /// valid Debian package name. Otherwise an invalid name would only be
+    /// noticed once the [Package] was written out and failed to parse back.
+    ///
+    /// ```
+    /// use deb::dependency::Package;
+    ///
+    /// assert_eq!("libc6", Package::new("libc6").unwrap().to_string());
+    /// assert!(Package::new("LibC6").is_err());
+    /// ```
+    pub fn new(name: &str) -> Result<Self, Error> {
+        if !is_valid_package_name(name) {
+            return Err(Error::InvalidPackageName);
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            ..Default::default()
+        })
+    }
+
+    /// Return the [Architecture] a resolver should look this [Package] up
+    /// for, on a multiarch system whose native architecture is `host`.
+    ///
+    /// An explicit qualifier (`libfoo:amd64`) must come from that
+    /// [Architecture]. No qualifier, `:native` and `:any` all default to
+    /// `host`, although with `:any` a `Multi-Arch: allowed` package from
+    /// another [Architecture] may satisfy it as well.
+    pub fn resolution_arch(&self, host: &Architecture) -> Architecture {
+        match &self.arch {
+            Some(arch) if *arch != architecture::ANY && *arch != architecture::NATIVE => {
+                arch.clone()
+            }
+            _ => host.clone(),
+        }
+    }
+
+    /// Return true if a package named `name` at [Version] `version`
+    /// satisfies this [Package], which is to say the names match, and the
+    /// `version` satisfies the `version_constraint` (if any).
+    ///
+    /// The `arch`, `arch_constraints` and
+    /// `build_profile_restriction_formula` aren't considered here.
+    pub fn satisfied_by(&self, name: &str, version: &Version) -> bool {
+        if self.name != name {
+            return false;
+        }
+        match &self.version_constraint {
+            Some(version_constraint) => version_constraint.satisfied_by(version),
+            None => true,
+        }
+    }
+
+    /// Return true if any virtual package in a provider's `Provides` field
+    /// satisfies this [Package].
+    ///
+    /// A versioned provide (`Provides: foo (= 1.2)`) satisfies this
+    /// [Package] if the provided version satisfies the
+    /// `version_constraint`, just like a real `foo` at version `1.2` would.
+    /// An unversioned provide (`Provides: foo`) only satisfies an
+    /// unversioned dependency on `foo`, never a versioned one such as
+    /// `foo (>= 1.0)`.
+    ///
+    /// ```
+    /// use deb::dependency::{Dependency, Package};
+    ///
+    /// let dependency: Dependency = "foo (>= 1.0)".parse().unwrap();
+    /// let package = &dependency.relations[0].packages[0];
+    ///
+    /// assert!(package.satisfied_by_provides(&"foo (= 1.2)".parse().unwrap()));
+    /// assert!(!package.satisfied_by_provides(&"foo".parse().unwrap()));
+    /// ```
+    pub fn satisfied_by_provides(&self, provides: &Dependency) -> bool {
+        provides
+            .relations
+            .iter()
+            .flat_map(|relation| relation.alternatives())
+            .any(|provide| {
+                if provide.name != self.name {
+                    return false;
+                }
+                let Some(version_constraint) = &self.version_constraint else {
+                    return true;
+                };
+                match &provide.version_constraint {
+                    Some(VersionConstraint {
+                        operator: VersionOperator::Equal,
+                        version,
+                    }) => version_constraint.satisfied_by(version),
+                    _ => false,
+                }
+            })
+    }
+
+    /// Return this [Package] with its `arch_constraints` set to `ac`.
+    pub fn with_arch_constraints(mut self, ac: ArchConstraints) -> Self {
+        self.arch_constraints = Some(ac);
+        self
+    }
+
+    /// Return this [Package] with its `version_constraint` set to `vc`.
+    pub fn with_version_constraint(mut self, vc: VersionConstraint) -> Self {
+        self.version_constraint = Some(vc);
+        self
+    }
+
+    /// Return this [Package] with its `build_profile_restriction_formula`
+    /// set to `bprf`.
+    pub fn with_build_profile_restriction_formula(
+        mut self,
+        bprf: BuildProfileRestrictionFormula,
+    ) -> Self {
+        self.build_profile_restriction_formula = Some(bprf);
+        self
+    }
+}
+
+/// A [Package] is always written out in the same order, no matter how it
+/// was parsed or built -- the name (and `:arch`), the version constraint,
+/// the architecture constraints, and finally the build profile
+/// restrictions. Constraints with nothing in them are left out entirely.
 impl std::fmt::Display for Package {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(f, "{}", self.name)?;
@@ -105,12 +255,17 @@ impl std::fmt::Display for Package {
             )?;
         }
 
-        if let Some(arch_constraints) = &self.arch_constraints {
+        if let Some(arch_constraints) = &self.arch_constraints
+            && !arch_constraints.arches.is_empty()
+        {
             write!(f, " [{arch_constraints}]")?;
         }
 
         if let Some(bprf) = &self.build_profile_restriction_formula {
             for build_profile_constraints in &bprf.build_profile_constraints {
+                if build_profile_constraints.build_profiles.is_empty() {
+                    continue;
+                }
                 write!(f, " <{build_profile_constraints}>")?;
             }
         }
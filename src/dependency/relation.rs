@@ -39,6 +39,65 @@ pub struct Relation {
     pub packages: Vec<Package>,
 }
 
+impl Relation {
+    /// Create a [Relation] which is only satisfied by the one [Package].
+    /// This is the same as `Relation::from(package)`.
+    pub fn single(package: Package) -> Relation {
+        Relation {
+            packages: vec![package],
+        }
+    }
+
+    /// Create a [Relation] which is satisfied by any one of the provided
+    /// [Package] values, such as `bar | baz`, in order of preference.
+    ///
+    /// ```
+    /// use deb::dependency::{Package, Relation};
+    ///
+    /// let relation = Relation::any_of(vec![
+    ///     Package::new("default-mta").unwrap(),
+    ///     Package::new("mail-transport-agent").unwrap(),
+    /// ]);
+    /// assert!(relation.is_alternative());
+    /// assert_eq!("default-mta | mail-transport-agent", relation.to_string());
+    /// ```
+    pub fn any_of(packages: Vec<Package>) -> Relation {
+        Relation { packages }
+    }
+
+    /// Iterate over each [Package] which would satisfy this [Relation].
+    ///
+    /// ```
+    /// use deb::dependency::Dependency;
+    ///
+    /// let dep: Dependency = "foo, bar | baz".parse().unwrap();
+    /// let relation = &dep.relations[1];
+    /// assert!(relation.is_alternative());
+    /// assert_eq!(
+    ///     vec!["bar", "baz"],
+    ///     relation
+    ///         .alternatives()
+    ///         .map(|package| package.name.as_str())
+    ///         .collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn alternatives(&self) -> impl Iterator<Item = &Package> {
+        self.packages.iter()
+    }
+
+    /// Return true if this [Relation] may be satisfied by more than one
+    /// [Package], such as `bar | baz`.
+    pub fn is_alternative(&self) -> bool {
+        self.packages.len() > 1
+    }
+}
+
+impl From<Package> for Relation {
+    fn from(package: Package) -> Self {
+        Relation::single(package)
+    }
+}
+
 impl std::fmt::Display for Relation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(
@@ -25,7 +25,8 @@ mod test {
         build_profile::BuildProfile,
         dependency::{
             ArchConstraint, ArchConstraints, BuildProfileConstraint, BuildProfileConstraints,
-            Dependency, Package, Relation, VersionConstraint, VersionOperator,
+            BuildProfileRestrictionFormula, Dependency, Error, Package, Relation,
+            VersionConstraint, VersionOperator,
         },
         version::Version,
     };
@@ -47,6 +48,8 @@ mod test {
     check_parse_fails!(unknown_relation, "foo {bar}");
     check_parse_fails!(no_package_arch_constraints, "[amd64]");
     check_parse_fails!(no_package_arch, ":amd64");
+    check_parse_fails!(package_arch_trailing_dash, "python3:any-");
+    check_parse_fails!(arch_constraint_trailing_dash, "foo [linux- any]");
 
     macro_rules! check_matches {
         ($name:ident, ( $( $dep:expr ),+ ), $check:expr) => {
@@ -87,14 +90,17 @@ mod test {
     macro_rules! simple_package {
         ($package:expr) => {
             Dependency {
-                relations: vec![Relation {
-                    packages: vec![$package],
-                }],
+                relations: vec![Relation::single($package)],
             }
         };
     }
 
     check_matches!(check_empty, "", Dependency { relations: vec![] });
+    check_matches!(
+        check_empty_whitespace,
+        (" ", "   ", "\t", "\n", " \n\t \n", "\r\n"),
+        Dependency { relations: vec![] }
+    );
     check_matches!(
         check_simple,
         "foo",
@@ -540,6 +546,322 @@ bar | baz
             ],
         }
     );
+
+    #[test]
+    fn default_package_to_string() {
+        assert_eq!("", Package::default().to_string());
+    }
+
+    #[test]
+    fn iter_alternatives_for() {
+        let dep: Dependency = "openssl | libressl, foo, bar | openssl:any (>= 3.0)"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            vec!["openssl | libressl", "bar | openssl:any (>= 3.0)"],
+            dep.iter_alternatives_for("openssl")
+                .map(|relation| relation.to_string())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, dep.iter_alternatives_for("foo").count());
+        assert_eq!(0, dep.iter_alternatives_for("baz").count());
+        assert_eq!(0, dep.iter_alternatives_for("openssl:any").count());
+    }
+
+    #[test]
+    fn package_display_canonical_order() {
+        let version_constraint = VersionConstraint {
+            operator: VersionOperator::LessThan,
+            version: "2.0".parse().unwrap(),
+        };
+        let arch_constraints = ArchConstraints {
+            arches: vec![ArchConstraint {
+                negated: true,
+                arch: architecture::I386,
+            }],
+        };
+        let bprf = BuildProfileRestrictionFormula {
+            build_profile_constraints: vec![BuildProfileConstraints {
+                build_profiles: vec![BuildProfileConstraint {
+                    negated: false,
+                    build_profile: BuildProfile::NoDoc,
+                }],
+            }],
+        };
+
+        let forwards = Package::new("foo")
+            .unwrap()
+            .with_version_constraint(version_constraint.clone())
+            .with_arch_constraints(arch_constraints.clone())
+            .with_build_profile_restriction_formula(bprf.clone());
+        let backwards = Package::new("foo")
+            .unwrap()
+            .with_build_profile_restriction_formula(bprf)
+            .with_arch_constraints(arch_constraints)
+            .with_version_constraint(version_constraint);
+
+        assert_eq!("foo (<< 2.0) [!i386] <nodoc>", forwards.to_string());
+        assert_eq!(forwards.to_string(), backwards.to_string());
+    }
+
+    #[test]
+    fn package_display_empty_constraints() {
+        let package = Package::new("foo")
+            .unwrap()
+            .with_arch_constraints(ArchConstraints { arches: vec![] })
+            .with_build_profile_restriction_formula(BuildProfileRestrictionFormula {
+                build_profile_constraints: vec![BuildProfileConstraints {
+                    build_profiles: vec![],
+                }],
+            });
+        assert_eq!("foo", package.to_string());
+    }
+
+    macro_rules! check_package_new {
+        ($name:ident, $package:expr, $valid:expr) => {
+            #[test]
+            fn $name() {
+                let package = Package::new($package);
+                assert_eq!($valid, package.is_ok(), "{:?}", package);
+                if let Ok(package) = package {
+                    assert_eq!($package, package.to_string());
+                }
+            }
+        };
+    }
+
+    check_package_new!(package_new_simple, "hello", true);
+    check_package_new!(package_new_digits, "0ad", true);
+    check_package_new!(package_new_symbols, "libstdc++6.0-dev", true);
+    check_package_new!(package_new_two, "xz", true);
+    check_package_new!(package_new_empty, "", false);
+    check_package_new!(package_new_one, "x", false);
+    check_package_new!(package_new_uppercase, "Hello", false);
+    check_package_new!(package_new_underscore, "foo_bar", false);
+    check_package_new!(package_new_leading_dash, "-foo", false);
+    check_package_new!(package_new_leading_plus, "+foo", false);
+    check_package_new!(package_new_arch, "foo:amd64", false);
+    check_package_new!(package_new_space, "foo bar", false);
+
+    #[test]
+    fn relation_alternatives() {
+        let dep: Dependency = "foo:any, bar | baz (>= 1.0) | quux".parse().unwrap();
+
+        assert!(!dep.relations[0].is_alternative());
+        assert_eq!(1, dep.relations[0].alternatives().count());
+
+        assert!(dep.relations[1].is_alternative());
+        assert_eq!(
+            vec!["bar", "baz (>= 1.0)", "quux"],
+            dep.relations[1]
+                .alternatives()
+                .map(|package| package.to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let (dep, skipped) = Dependency::parse_lenient(
+            "${shlibs:Depends},
+ libc6 (>= 2.36),
+ foo | bar [amd64],
+ ${misc:Depends},
+ baz (>= ${binary:Version}),
+ quux,",
+        );
+
+        assert_eq!("libc6 (>= 2.36), foo | bar [amd64], quux", dep.to_string());
+        assert_eq!(
+            vec![
+                "${shlibs:Depends}",
+                "${misc:Depends}",
+                "baz (>= ${binary:Version})"
+            ],
+            skipped
+                .iter()
+                .map(|skipped| skipped.relation.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(matches!(skipped[0].error, Error::Parse(_)));
+    }
+
+    #[test]
+    fn parse_lenient_matches_strict() {
+        for input in ["", "foo", "foo, bar | baz (<< 1.0) [!i386] <!nocheck>"] {
+            let (dep, skipped) = Dependency::parse_lenient(input);
+            assert!(skipped.is_empty());
+            assert_eq!(input.parse::<Dependency>().unwrap(), dep);
+        }
+    }
+
+    #[test]
+    fn explain() {
+        let dep: Dependency = "a, b (>= 1.0) | c [amd64]".parse().unwrap();
+        assert_eq!(
+            "\
+1. a
+2. b
+     version: >= 1.0
+   | c
+     architectures: amd64
+",
+            dep.explain()
+        );
+
+        let dep: Dependency = "a:any <!nocheck> <cross>, b, c, d, e, f, g, h, i, j | k:arm64"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            "\
+1. a
+     arch: any
+     build profiles: <!nocheck> <cross>
+2. b
+3. c
+4. d
+5. e
+6. f
+7. g
+8. h
+9. i
+10. j
+    | k
+      arch: arm64
+",
+            dep.explain()
+        );
+
+        assert_eq!("", Dependency::default().explain());
+    }
+
+    #[test]
+    fn merged() {
+        let pre_depends: Dependency = "dpkg (>= 1.22), libc6".parse().unwrap();
+        let depends: Dependency = "libc6, foo | bar, dpkg (>= 1.21)".parse().unwrap();
+
+        assert_eq!(
+            "dpkg (>= 1.22), libc6, foo | bar, dpkg (>= 1.21)",
+            Dependency::merged(&[&pre_depends, &depends]).to_string()
+        );
+        assert_eq!(
+            depends,
+            Dependency::merged(&[&Dependency::default(), &depends])
+        );
+        assert!(Dependency::merged(&[]).relations.is_empty());
+    }
+
+    macro_rules! check_resolution_arch {
+        ($name:ident, $dep:expr, $host:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let dep: Dependency = $dep.parse().unwrap();
+                let package = &dep.relations[0].packages[0];
+                assert_eq!($expected, package.resolution_arch(&$host));
+            }
+        };
+    }
+
+    check_resolution_arch!(
+        resolution_arch_none,
+        "libfoo",
+        architecture::ARM64,
+        architecture::ARM64
+    );
+    check_resolution_arch!(
+        resolution_arch_qualified,
+        "libfoo:amd64",
+        architecture::ARM64,
+        architecture::AMD64
+    );
+    check_resolution_arch!(
+        resolution_arch_any,
+        "python3:any",
+        architecture::ARM64,
+        architecture::ARM64
+    );
+    check_resolution_arch!(
+        resolution_arch_native,
+        "gcc:native",
+        architecture::ARM64,
+        architecture::ARM64
+    );
+
+    #[test]
+    fn package_builder() {
+        let package = Package::new("foo")
+            .unwrap()
+            .with_version_constraint(VersionConstraint {
+                operator: VersionOperator::GreaterThanOrEqual,
+                version: "1.0".parse().unwrap(),
+            })
+            .with_arch_constraints(ArchConstraints {
+                arches: vec![ArchConstraint {
+                    negated: false,
+                    arch: architecture::AMD64,
+                }],
+            })
+            .with_build_profile_restriction_formula(BuildProfileRestrictionFormula {
+                build_profile_constraints: vec![BuildProfileConstraints {
+                    build_profiles: vec![BuildProfileConstraint {
+                        negated: true,
+                        build_profile: BuildProfile::NoCheck,
+                    }],
+                }],
+            });
+
+        assert_eq!("foo (>= 1.0) [amd64] <!nocheck>", package.to_string());
+
+        let dep: Dependency = "foo (>= 1.0) [amd64] <!nocheck>".parse().unwrap();
+        assert_eq!(package, dep.relations[0].packages[0]);
+    }
+
+    #[test]
+    fn relation_constructors() {
+        let foo = Package::new("foo").unwrap();
+        let bar = Package::new("bar").unwrap();
+
+        let dep: Dependency = "foo, foo | bar".parse().unwrap();
+        assert_eq!(dep.relations[0], Relation::single(foo.clone()));
+        assert_eq!(dep.relations[0], Relation::from(foo.clone()));
+        assert_eq!(dep.relations[1], Relation::any_of(vec![foo, bar]));
+    }
+
+    macro_rules! check_satisfied_by_provides {
+        ($name:ident, $dep:expr, $provides:expr, $satisfied:expr) => {
+            #[test]
+            fn $name() {
+                let dep: Dependency = $dep.parse().unwrap();
+                let provides: Dependency = $provides.parse().unwrap();
+                assert_eq!(
+                    $satisfied,
+                    dep.relations[0].packages[0].satisfied_by_provides(&provides)
+                );
+            }
+        };
+    }
+
+    check_satisfied_by_provides!(provides_versioned, "foo (>= 1.0)", "foo (= 1.2)", true);
+    check_satisfied_by_provides!(provides_unversioned, "foo (>= 1.0)", "foo", false);
+    check_satisfied_by_provides!(provides_too_old, "foo (>= 1.0)", "foo (= 0.9)", false);
+    check_satisfied_by_provides!(provides_any_version, "foo", "foo (= 1.2)", true);
+    check_satisfied_by_provides!(provides_bare, "foo", "foo", true);
+    check_satisfied_by_provides!(provides_other, "foo", "bar, baz (= 1.0)", false);
+    check_satisfied_by_provides!(provides_list, "foo (<< 2)", "bar, foo (= 1.2)", true);
+    check_satisfied_by_provides!(provides_not_equal, "foo (>= 1.0)", "foo (>= 1.2)", false);
+
+    #[test]
+    fn satisfied_by() {
+        let dep: Dependency = "foo (>= 1.0), bar".parse().unwrap();
+        let foo = &dep.relations[0].packages[0];
+        let bar = &dep.relations[1].packages[0];
+        let version: Version = "1.0".parse().unwrap();
+        assert!(foo.satisfied_by("foo", &version));
+        assert!(!foo.satisfied_by("foo", &"1.0~rc1".parse().unwrap()));
+        assert!(!foo.satisfied_by("bar", &version));
+        assert!(bar.satisfied_by("bar", &version));
+    }
 }
 
 // vim: foldmethod=marker
@@ -83,6 +83,20 @@ pub struct VersionConstraint {
     pub version: Version,
 }
 
+impl VersionConstraint {
+    /// Return true if the provided [Version] satisfies this constraint.
+    pub fn satisfied_by(&self, version: &Version) -> bool {
+        let ordering = version.cmp(&self.version);
+        match self.operator {
+            VersionOperator::Equal => ordering.is_eq(),
+            VersionOperator::GreaterThan => ordering.is_gt(),
+            VersionOperator::LessThan => ordering.is_lt(),
+            VersionOperator::GreaterThanOrEqual => ordering.is_ge(),
+            VersionOperator::LessThanOrEqual => ordering.is_le(),
+        }
+    }
+}
+
 impl std::fmt::Display for VersionConstraint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} {}", self.operator, self.version)
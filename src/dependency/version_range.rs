@@ -0,0 +1,293 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::{VersionConstraint, VersionOperator};
+use crate::version::Version;
+use std::cmp::Ordering;
+
+/// One end of a [VersionRange].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VersionBound {
+    /// [Version] at the edge of the range.
+    pub version: Version,
+
+    /// If true, the [VersionBound::version] itself is inside the range.
+    pub inclusive: bool,
+}
+
+/// Span of [Version] values, such as those which satisfy one or more
+/// [VersionConstraint]s. A missing bound leaves that end of the range open.
+///
+/// ```
+/// use deb::dependency::{VersionConstraint, VersionOperator, VersionRange};
+///
+/// let at_least = VersionConstraint {
+///     operator: VersionOperator::GreaterThanOrEqual,
+///     version: "1.0".parse().unwrap(),
+/// };
+/// let before = VersionConstraint {
+///     operator: VersionOperator::LessThan,
+///     version: "2.0".parse().unwrap(),
+/// };
+///
+/// let range: VersionRange = at_least.intersect(&before).unwrap();
+/// assert!(range.contains(&"1.5".parse().unwrap()));
+/// assert!(!range.contains(&"2.0".parse().unwrap()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct VersionRange {
+    /// Lowest [Version] in the range, or `None` if there's no lower limit.
+    pub lower: Option<VersionBound>,
+
+    /// Highest [Version] in the range, or `None` if there's no upper limit.
+    pub upper: Option<VersionBound>,
+}
+
+impl VersionRange {
+    /// Return true if the provided [Version] falls within this range.
+    pub fn contains(&self, version: &Version) -> bool {
+        if let Some(lower) = &self.lower {
+            match version.cmp(&lower.version) {
+                Ordering::Less => return false,
+                Ordering::Equal if !lower.inclusive => return false,
+                _ => {}
+            }
+        }
+        if let Some(upper) = &self.upper {
+            match version.cmp(&upper.version) {
+                Ordering::Greater => return false,
+                Ordering::Equal if !upper.inclusive => return false,
+                _ => {}
+            }
+        }
+        true
+    }
+
+    /// Return true if no [Version] can fall within this range.
+    pub fn is_empty(&self) -> bool {
+        let (Some(lower), Some(upper)) = (&self.lower, &self.upper) else {
+            return false;
+        };
+        match lower.version.cmp(&upper.version) {
+            Ordering::Less => false,
+            Ordering::Equal => !(lower.inclusive && upper.inclusive),
+            Ordering::Greater => true,
+        }
+    }
+
+    /// Return the range of [Version]s which fall within both this range and
+    /// the `other` range, or `None` if there are none.
+    pub fn intersect(&self, other: &VersionRange) -> Option<VersionRange> {
+        let range = VersionRange {
+            lower: tightest(&self.lower, &other.lower, Ordering::Greater),
+            upper: tightest(&self.upper, &other.upper, Ordering::Less),
+        };
+        if range.is_empty() {
+            return None;
+        }
+        Some(range)
+    }
+}
+
+/// Pick the tighter of two bounds, where `tighter` is the [Ordering] of
+/// the tighter [Version] relative to the looser one. When both bounds are
+/// at the same [Version], an exclusive bound is tighter.
+fn tightest(
+    a: &Option<VersionBound>,
+    b: &Option<VersionBound>,
+    tighter: Ordering,
+) -> Option<VersionBound> {
+    match (a, b) {
+        (None, None) => None,
+        (Some(bound), None) | (None, Some(bound)) => Some(bound.clone()),
+        (Some(a), Some(b)) => Some(match a.version.cmp(&b.version) {
+            Ordering::Equal => VersionBound {
+                version: a.version.clone(),
+                inclusive: a.inclusive && b.inclusive,
+            },
+            ordering if ordering == tighter => a.clone(),
+            _ => b.clone(),
+        }),
+    }
+}
+
+impl From<&VersionConstraint> for VersionRange {
+    fn from(constraint: &VersionConstraint) -> Self {
+        let bound = |inclusive| {
+            Some(VersionBound {
+                version: constraint.version.clone(),
+                inclusive,
+            })
+        };
+        match constraint.operator {
+            VersionOperator::Equal => VersionRange {
+                lower: bound(true),
+                upper: bound(true),
+            },
+            VersionOperator::GreaterThan => VersionRange {
+                lower: bound(false),
+                upper: None,
+            },
+            VersionOperator::GreaterThanOrEqual => VersionRange {
+                lower: bound(true),
+                upper: None,
+            },
+            VersionOperator::LessThan => VersionRange {
+                lower: None,
+                upper: bound(false),
+            },
+            VersionOperator::LessThanOrEqual => VersionRange {
+                lower: None,
+                upper: bound(true),
+            },
+        }
+    }
+}
+
+impl From<VersionConstraint> for VersionRange {
+    fn from(constraint: VersionConstraint) -> Self {
+        (&constraint).into()
+    }
+}
+
+impl VersionConstraint {
+    /// Return the range of [Version]s which satisfy both this constraint
+    /// and the `other` constraint, or `None` if the two constraints
+    /// contradict each other, such as `(= 1.0)` and `(= 2.0)`.
+    pub fn intersect(&self, other: &VersionConstraint) -> Option<VersionRange> {
+        VersionRange::from(self).intersect(&other.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraint(operator: VersionOperator, version: &str) -> VersionConstraint {
+        VersionConstraint {
+            operator,
+            version: version.parse().unwrap(),
+        }
+    }
+
+    fn bound(version: &str, inclusive: bool) -> Option<VersionBound> {
+        Some(VersionBound {
+            version: version.parse().unwrap(),
+            inclusive,
+        })
+    }
+
+    macro_rules! check_intersect {
+        ($name:ident, ($op1:ident, $v1:expr), ($op2:ident, $v2:expr), $expected:expr) => {
+            #[test]
+            fn $name() {
+                let c1 = constraint(VersionOperator::$op1, $v1);
+                let c2 = constraint(VersionOperator::$op2, $v2);
+                let expected: Option<VersionRange> = $expected;
+                assert_eq!(expected, c1.intersect(&c2));
+                assert_eq!(expected, c2.intersect(&c1));
+            }
+        };
+    }
+
+    check_intersect!(
+        window,
+        (GreaterThanOrEqual, "1.0"),
+        (LessThan, "2.0"),
+        Some(VersionRange {
+            lower: bound("1.0", true),
+            upper: bound("2.0", false),
+        })
+    );
+
+    check_intersect!(
+        tighter_lower,
+        (GreaterThanOrEqual, "1.0"),
+        (GreaterThan, "1.5"),
+        Some(VersionRange {
+            lower: bound("1.5", false),
+            upper: None,
+        })
+    );
+
+    check_intersect!(
+        tighter_upper,
+        (LessThanOrEqual, "3.0"),
+        (LessThan, "2.0~rc1"),
+        Some(VersionRange {
+            lower: None,
+            upper: bound("2.0~rc1", false),
+        })
+    );
+
+    check_intersect!(
+        same_version_exclusive_wins,
+        (GreaterThanOrEqual, "1.0"),
+        (GreaterThan, "1.0"),
+        Some(VersionRange {
+            lower: bound("1.0", false),
+            upper: None,
+        })
+    );
+
+    check_intersect!(
+        equal_within,
+        (Equal, "1.5"),
+        (LessThan, "2.0"),
+        Some(VersionRange {
+            lower: bound("1.5", true),
+            upper: bound("1.5", true),
+        })
+    );
+
+    check_intersect!(
+        touching_inclusive,
+        (GreaterThanOrEqual, "1.0"),
+        (LessThanOrEqual, "1.0"),
+        Some(VersionRange {
+            lower: bound("1.0", true),
+            upper: bound("1.0", true),
+        })
+    );
+
+    check_intersect!(equal_mismatch, (Equal, "1.0"), (Equal, "2.0"), None);
+    check_intersect!(disjoint, (LessThan, "1.0"), (GreaterThan, "2.0"), None);
+    check_intersect!(
+        touching_exclusive,
+        (GreaterThanOrEqual, "1.0"),
+        (LessThan, "1.0"),
+        None
+    );
+    check_intersect!(equal_outside, (Equal, "2.0"), (LessThan, "2.0"), None);
+
+    #[test]
+    fn contains() {
+        let range = constraint(VersionOperator::GreaterThan, "1.0")
+            .intersect(&constraint(VersionOperator::LessThanOrEqual, "2.0"))
+            .unwrap();
+        assert!(!range.contains(&"1.0".parse().unwrap()));
+        assert!(range.contains(&"1.0-1".parse().unwrap()));
+        assert!(range.contains(&"2.0".parse().unwrap()));
+        assert!(!range.contains(&"2.0-1".parse().unwrap()));
+        assert!(VersionRange::default().contains(&"1:0".parse().unwrap()));
+    }
+}
+
+// vim: foldmethod=marker
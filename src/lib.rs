@@ -69,6 +69,7 @@
 //! | `hex`     | Enable parsing ASCII hex values using the [hex] crate                    |
 //! | `serde`   | Enable support for encoding and decoding using [serde]                   |
 //! | `sequoia` | Enable support for validating OpenPGP signatures using [sequoia_openpgp] |
+//! | `subtle`  | Enable constant time digest comparisons using the [subtle] crate.        |
 //! | `tokio`   | Enable support for the [tokio] crate.                                    |
 //!
 //! # Feature `chrono`
@@ -93,6 +94,12 @@
 //! OpenPGP implementation. This will export a few helpers throughout
 //! the crate, such as [control::de::from_clearsigned_str].
 //!
+//! # Feature `subtle`
+//!
+//! Enable [control::Digest::ct_eq], which compares a computed digest against
+//! an expected one in constant time, for places where leaking how much of the
+//! digest matched through timing is a concern.
+//!
 //! # Feature `tokio`
 //!
 //! Enable functions to handle places where there's an i/o boundary that is
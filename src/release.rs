@@ -57,7 +57,10 @@
 //! # Feature `chrono`
 //!
 //! This feature will enable the loading of dates related to release
-//! information via [Release::released_on] and [Release::eol_on].
+//! information via [Release::released_on] and [Release::eol_on], and
+//! enable checking support status with [Release::is_supported_on] and
+//! friends. Without it, [Release::is_currently_supported] is still
+//! available, but always returns `false`, since no release dates are known.
 
 use crate::architecture::{self, Architecture};
 use std::borrow::Cow;
@@ -85,6 +88,84 @@ pub struct Release {
     eol_on: Option<NaiveDate>,
 }
 
+/// Moving suite names used to refer to a [Release] relative to the current
+/// `stable` release, rather than by its codename.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Suite {
+    /// `unstable`, which is always `sid`.
+    Unstable,
+
+    /// `testing`, the next [Release] to become `stable`.
+    Testing,
+
+    /// `stable`, the most recent [Release].
+    Stable,
+
+    /// `oldstable`, the [Release] before `stable`, if it's still supported.
+    OldStable,
+}
+
+impl std::fmt::Display for Suite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Unstable => "unstable",
+                Self::Testing => "testing",
+                Self::Stable => "stable",
+                Self::OldStable => "oldstable",
+            }
+        )
+    }
+}
+
+/// [Architecture]s which were added or dropped as supported ports between
+/// two [Release]s, as returned by [architecture_delta].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArchDelta {
+    /// [Architecture]s supported by the new [Release], but not the old one.
+    pub added: Vec<Architecture>,
+
+    /// [Architecture]s supported by the old [Release], but not the new one.
+    pub removed: Vec<Architecture>,
+}
+
+/// Compare the supported [Release::architectures] of the `old` and `new`
+/// [Release], to find the ports which were added or dropped between them.
+///
+/// Releases which haven't happened yet (like [FORKY]) don't have any
+/// [Release::architectures] listed, since nothing is known until they
+/// release. Rather than reporting every port as dropped (or added), there's
+/// no delta if either [Release] has no architectures listed.
+///
+/// ```
+/// use deb::{architecture, release};
+///
+/// let delta = release::architecture_delta(&release::BOOKWORM, &release::TRIXIE);
+/// assert_eq!(vec![architecture::RISCV64], delta.added);
+/// ```
+pub fn architecture_delta(old: &Release, new: &Release) -> ArchDelta {
+    if old.architectures.is_empty() || new.architectures.is_empty() {
+        return ArchDelta::default();
+    }
+
+    ArchDelta {
+        added: new
+            .architectures
+            .iter()
+            .filter(|arch| !old.architectures.contains(arch))
+            .cloned()
+            .collect(),
+        removed: old
+            .architectures
+            .iter()
+            .filter(|arch| !new.architectures.contains(arch))
+            .cloned()
+            .collect(),
+    }
+}
+
 macro_rules! cow {
     ( $str:expr ) => {
         Cow::Borrowed($str)
@@ -492,10 +573,13 @@ pub const BOOKWORM: Release = Release {
 
 /// Debian 13, Trixie
 ///
+/// [Release Announcement](https://www.debian.org/News/2025/20250809)
+///
 /// `trixie` is the first Debian release to support the
-/// [architecture::RISCV64] [Architecture]. The [architecture::I386] port
-/// is also in a bit of a limbo but not officially dropped, although it
-/// no longer has an installer or kernel.
+/// [architecture::RISCV64] [Architecture], and the last one to support
+/// [architecture::ARMEL]. The [architecture::I386] port is also in a bit
+/// of a limbo but not officially dropped, although it no longer has an
+/// installer or kernel.
 pub const TRIXIE: Release = Release {
     name: cow!("trixie"),
     version: cow!("13"),
@@ -505,6 +589,7 @@ pub const TRIXIE: Release = Release {
     architectures: cow!(&[
         architecture::AMD64,
         architecture::ARM64,
+        architecture::ARMEL,
         architecture::ARMHF,
         architecture::PPC64EL,
         architecture::RISCV64,
@@ -576,7 +661,7 @@ mod chrono {
     const RELEASE_HORIZON: NaiveDate = date!(2025 / 6 / 1).unwrap();
 
     use super::*;
-    use ::chrono::{NaiveDate, Utc};
+    use ::chrono::{Months, NaiveDate, Utc};
 
     impl Release {
         /// Date on which this release was promoted from Debian
@@ -595,6 +680,47 @@ mod chrono {
         pub fn eol_on(&self) -> Option<&NaiveDate> {
             self.eol_on.as_ref()
         }
+
+        /// Check to see if this [Release] was (or is) supported by the
+        /// Debian project as a release on the provided date. Releases
+        /// which haven't been released yet are not supported.
+        pub fn is_supported_on(&self, date: &NaiveDate) -> bool {
+            let Some(released_on) = &self.released_on else {
+                return false;
+            };
+            if released_on >= date {
+                return false;
+            }
+            match &self.eol_on {
+                Some(eol_on) => date < eol_on,
+                None => true,
+            }
+        }
+
+        /// Check to see if this [Release] was (or is) in its
+        /// [LTS](https://wiki.debian.org/LTS) window on the provided date,
+        /// which runs for two years after its [Release::eol_on] date.
+        /// Releases still under full support (as with
+        /// [Release::is_supported_on]) are not in LTS.
+        pub fn is_lts_on(&self, date: &NaiveDate) -> bool {
+            let Some(eol_on) = &self.eol_on else {
+                return false;
+            };
+            let Some(lts_eol_on) = eol_on.checked_add_months(Months::new(24)) else {
+                return false;
+            };
+            eol_on <= date && *date < lts_eol_on
+        }
+
+        /// Check to see if this [Release] is supported by the Debian
+        /// project as a release at the time of this function call.
+        ///
+        /// Without the `chrono` feature, no release dates are known, and
+        /// this always returns `false`.
+        pub fn is_currently_supported(&self) -> bool {
+            let today = Utc::now().naive_utc().date();
+            self.is_supported_on(&today)
+        }
     }
 
     /// Filter the set of all [RELEASES] to just the [Release]s which are or
@@ -602,14 +728,65 @@ mod chrono {
     pub fn supported_on(date: &NaiveDate) -> Vec<Release> {
         RELEASES
             .iter()
-            .filter(|rel| rel.released_on.is_some())
-            .filter(|rel| match &rel.released_on {
-                Some(release_date) => release_date < date,
-                None => true,
-            })
-            .filter(|rel| match &rel.eol_on {
-                Some(eol_date) => date < eol_date,
-                None => true,
+            .filter(|rel| rel.is_supported_on(date))
+            .cloned()
+            .collect()
+    }
+
+    /// Filter the set of all [RELEASES] to just the [Release]s which were
+    /// (or are) in their LTS window at the provided time, as with
+    /// [Release::is_lts_on]. This doesn't include any [Release]s still
+    /// under full support, which are returned by [supported_on].
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use deb::release::{self, BUSTER};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+    /// assert_eq!(vec![BUSTER], release::lts_on(&date));
+    /// ```
+    pub fn lts_on(date: &NaiveDate) -> Vec<Release> {
+        RELEASES
+            .iter()
+            .filter(|rel| rel.is_lts_on(date))
+            .cloned()
+            .collect()
+    }
+
+    /// Filter the set of all [RELEASES] to just the [Release]s for which
+    /// [Release::is_supported_on] is true on at least one day from `start`
+    /// through to `end`. Releases which are still supported (without an
+    /// end of life date) are supported through to any `end`, and releases
+    /// which haven't been released yet are never included.
+    ///
+    /// Since [Release::is_supported_on] doesn't count the day of the
+    /// release or the end of life date itself, a release made on `end`,
+    /// or which reached its end of life on `start`, isn't included.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use deb::release::{self, SQUEEZE, WHEEZY};
+    ///
+    /// let releases = release::released_between(
+    ///     &NaiveDate::from_ymd_opt(2013, 1, 1).unwrap(),
+    ///     &NaiveDate::from_ymd_opt(2013, 12, 31).unwrap(),
+    /// );
+    /// assert_eq!(vec![WHEEZY, SQUEEZE], releases);
+    /// ```
+    pub fn released_between(start: &NaiveDate, end: &NaiveDate) -> Vec<Release> {
+        RELEASES
+            .iter()
+            .filter(|rel| {
+                let Some(released_on) = &rel.released_on else {
+                    return false;
+                };
+                if released_on >= end {
+                    return false;
+                }
+                match &rel.eol_on {
+                    Some(eol_on) => start < eol_on,
+                    None => true,
+                }
             })
             .cloned()
             .collect()
@@ -651,6 +828,47 @@ mod chrono {
         Some([testing, stable])
     }
 
+    /// Determine which [Suite] the [Release] named `name` (such as
+    /// `bookworm`) was known as on the provided date, using
+    /// [guess_release_suites_on]. `sid` is always [Suite::Unstable].
+    ///
+    /// This returns `None` if the named release wasn't one of `testing`,
+    /// `stable` or a supported `oldstable` at that time, or if the date is
+    /// too far in the future for [guess_release_suites_on] to answer.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use deb::release::{self, Suite};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// assert_eq!(
+    ///     Some(Suite::Stable),
+    ///     release::suite_for_codename("bookworm", &date)
+    /// );
+    /// ```
+    pub fn suite_for_codename(name: &str, on: &NaiveDate) -> Option<Suite> {
+        if name == "sid" {
+            return Some(Suite::Unstable);
+        }
+
+        let [testing, stable] = guess_release_suites_on(on)?;
+        if testing.name == name {
+            return Some(Suite::Testing);
+        }
+        if stable.name == name {
+            return Some(Suite::Stable);
+        }
+
+        // if this fails something very bad has happened.
+        let stable_idx = RELEASES.iter().position(|e| *e == stable).unwrap();
+        let oldstable = RELEASES.get(stable_idx + 1)?;
+        if oldstable.name == name && oldstable.is_supported_on(on) {
+            return Some(Suite::OldStable);
+        }
+
+        None
+    }
+
     /// Filter the set of all [RELEASES] to just the [Release]s which are or
     /// were supported at the time of this function call.
     pub fn supported() -> Vec<Release> {
@@ -707,6 +925,151 @@ mod chrono {
             assert_eq!(vec![SQUEEZE], supported_releases);
         }
 
+        #[test]
+        fn test_lts_on() {
+            let date = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+            assert_eq!(vec![BUSTER], lts_on(&date));
+            assert!(!supported_on(&date).contains(&BUSTER));
+
+            // the day buster's LTS ended, bullseye's had already started.
+            let date = NaiveDate::from_ymd_opt(2024, 9, 10).unwrap();
+            assert_eq!(vec![BULLSEYE], lts_on(&date));
+
+            // and the day bullseye reached its end of life, it's in LTS.
+            let date = NaiveDate::from_ymd_opt(2024, 8, 14).unwrap();
+            assert_eq!(vec![BULLSEYE, BUSTER], lts_on(&date));
+            assert!(!BULLSEYE.is_supported_on(&date));
+            assert!(!TRIXIE.is_lts_on(&date));
+        }
+
+        #[test]
+        fn test_released_between() {
+            assert_eq!(
+                vec![JESSIE, WHEEZY, SQUEEZE, LENNY, ETCH],
+                released_between(
+                    &NaiveDate::from_ymd_opt(2010, 1, 1).unwrap(),
+                    &NaiveDate::from_ymd_opt(2015, 12, 31).unwrap(),
+                )
+            );
+
+            // a single day is the same as supported_on
+            let date = NaiveDate::from_ymd_opt(2012, 6, 26).unwrap();
+            assert_eq!(supported_on(&date), released_between(&date, &date));
+
+            // end of life and release days aren't counted, the same as
+            // is_supported_on.
+            assert_eq!(
+                vec![JESSIE],
+                released_between(
+                    &NaiveDate::from_ymd_opt(2016, 4, 25).unwrap(),
+                    &NaiveDate::from_ymd_opt(2017, 6, 17).unwrap(),
+                )
+            );
+
+            // every day from start to end is checked, but the release day
+            // and end of life day don't count.
+            let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let wheezy_between = |start, end| released_between(&start, &end).contains(&WHEEZY);
+            assert!(!wheezy_between(day(2013, 1, 1), day(2013, 5, 4)));
+            assert!(wheezy_between(day(2013, 1, 1), day(2013, 5, 5)));
+            assert!(wheezy_between(day(2016, 4, 24), day(2017, 1, 1)));
+            assert!(!wheezy_between(day(2016, 4, 25), day(2017, 1, 1)));
+
+            // unreleased entries are never included, even far in the future.
+            let releases = released_between(
+                &NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                &NaiveDate::from_ymd_opt(2100, 1, 1).unwrap(),
+            );
+            assert_eq!(vec![TRIXIE, BOOKWORM], releases);
+        }
+
+        #[test]
+        fn test_suite_for_codename() {
+            let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+            assert_eq!(Some(Suite::Unstable), suite_for_codename("sid", &date));
+            assert_eq!(Some(Suite::Testing), suite_for_codename("trixie", &date));
+            assert_eq!(Some(Suite::Stable), suite_for_codename("bookworm", &date));
+            assert_eq!(
+                Some(Suite::OldStable),
+                suite_for_codename("bullseye", &date)
+            );
+            assert_eq!(None, suite_for_codename("buster", &date));
+            assert_eq!(None, suite_for_codename("forky", &date));
+            assert_eq!(None, suite_for_codename("nonsense", &date));
+
+            // bullseye was out of support, so there's no oldstable.
+            let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+            assert_eq!(None, suite_for_codename("bullseye", &date));
+            assert_eq!(Some(Suite::Stable), suite_for_codename("bookworm", &date));
+
+            let date = NaiveDate::from_ymd_opt(2012, 6, 26).unwrap();
+            assert_eq!(Some(Suite::Testing), suite_for_codename("wheezy", &date));
+            assert_eq!(Some(Suite::Stable), suite_for_codename("squeeze", &date));
+            assert_eq!(None, suite_for_codename("lenny", &date));
+
+            // past the release horizon, only sid is known.
+            let date = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+            assert_eq!(None, suite_for_codename("trixie", &date));
+            assert_eq!(Some(Suite::Unstable), suite_for_codename("sid", &date));
+        }
+
+        #[test]
+        fn test_suite_display() {
+            assert_eq!("unstable", Suite::Unstable.to_string());
+            assert_eq!("testing", Suite::Testing.to_string());
+            assert_eq!("stable", Suite::Stable.to_string());
+            assert_eq!("oldstable", Suite::OldStable.to_string());
+        }
+
+        #[test]
+        fn test_is_supported_on() {
+            let date = NaiveDate::from_ymd_opt(2023, 7, 1).unwrap();
+            assert!(BOOKWORM.is_supported_on(&date));
+            assert!(BULLSEYE.is_supported_on(&date));
+            assert!(!BUSTER.is_supported_on(&date));
+            assert!(!TRIXIE.is_supported_on(&date));
+            assert!(!DUKE.is_supported_on(&date));
+        }
+
+        #[test]
+        fn test_is_currently_supported() {
+            assert!(!BUZZ.is_currently_supported());
+            assert!(!DUKE.is_currently_supported());
+            assert_eq!(
+                supported(),
+                RELEASES
+                    .into_iter()
+                    .filter(|rel| rel.is_currently_supported())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_released_architectures() {
+            for release in RELEASES {
+                if release.released_on().is_some() {
+                    assert!(
+                        !release.architectures.is_empty(),
+                        "{} was released without any architectures",
+                        release.name
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_trixie_architectures() {
+            assert!(TRIXIE.architectures.contains(&architecture::RISCV64));
+            for arch in [
+                architecture::AMD64,
+                architecture::ARM64,
+                architecture::S390X,
+            ] {
+                assert!(BOOKWORM.architectures.contains(&arch));
+                assert!(TRIXIE.architectures.contains(&arch));
+            }
+        }
+
         #[test]
         fn test_supported_architectures_on() {
             let supported_architectures =
@@ -756,10 +1119,99 @@ mod chrono {
     }
 }
 
+#[cfg(not(feature = "chrono"))]
+impl Release {
+    /// Check to see if this [Release] is supported by the Debian project
+    /// as a release at the time of this function call.
+    ///
+    /// Without the `chrono` feature, the release dates aren't loaded (so
+    /// there's never an end of life date to check against), and this
+    /// always returns `false`. Enable the `chrono` feature to get a real
+    /// answer.
+    pub fn is_currently_supported(&self) -> bool {
+        false
+    }
+}
+
 #[cfg(feature = "chrono")]
 pub use chrono::{
-    guess_release_suites_on, supported, supported_architectures, supported_architectures_on,
-    supported_on,
+    guess_release_suites_on, lts_on, released_between, suite_for_codename, supported,
+    supported_architectures, supported_architectures_on, supported_on,
 };
 
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::version::Version;
+
+    #[cfg(not(feature = "chrono"))]
+    #[test]
+    fn test_is_currently_supported_without_chrono() {
+        assert!(RELEASES.iter().all(|rel| !rel.is_currently_supported()));
+    }
+
+    #[test]
+    fn test_releases_newest_first() {
+        // RELEASES is the single source of truth for releases, so make sure
+        // nothing is listed twice and the version numbers line up with
+        // the order.
+        for pair in RELEASES.windows(2) {
+            let [newer, older] = pair else { unreachable!() };
+            assert_ne!(newer.name, older.name);
+            assert!(
+                newer.version.parse::<Version>().unwrap()
+                    > older.version.parse::<Version>().unwrap(),
+                "{} ({}) should be newer than {} ({})",
+                newer.name,
+                newer.version,
+                older.name,
+                older.version,
+            );
+        }
+    }
+
+    #[test]
+    fn test_architecture_delta() {
+        assert_eq!(
+            ArchDelta {
+                added: vec![architecture::MIPS64EL],
+                removed: vec![architecture::POWERPC],
+            },
+            architecture_delta(&JESSIE, &STRETCH)
+        );
+        assert_eq!(
+            ArchDelta {
+                added: vec![architecture::POWERPC],
+                removed: vec![architecture::MIPS64EL],
+            },
+            architecture_delta(&STRETCH, &JESSIE)
+        );
+        assert_eq!(ArchDelta::default(), architecture_delta(&STRETCH, &BUSTER));
+        assert_eq!(
+            ArchDelta {
+                added: vec![architecture::RISCV64],
+                removed: vec![
+                    architecture::I386,
+                    architecture::MIPS64EL,
+                    architecture::MIPSEL,
+                ],
+            },
+            architecture_delta(&BOOKWORM, &TRIXIE)
+        );
+    }
+
+    #[test]
+    fn test_architecture_delta_unreleased() {
+        assert_eq!(ArchDelta::default(), architecture_delta(&TRIXIE, &FORKY));
+        assert_eq!(ArchDelta::default(), architecture_delta(&FORKY, &TRIXIE));
+    }
+
+    #[test]
+    fn test_forky_duke() {
+        assert_eq!("14", FORKY.version);
+        assert_eq!("15", DUKE.version);
+        assert_eq!(Some(&DUKE), RELEASES.first());
+    }
+}
+
 // vim: foldmethod=marker
@@ -0,0 +1,141 @@
+// {{{ Copyright (c) Paul R. Tagliamonte <paultag@debian.org>, 2024
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+// THE SOFTWARE. }}}
+
+use super::{Version, compare::compare_version_str};
+use std::cmp::Ordering;
+
+/// Kind of change between two [Version]s of a package, as returned by
+/// [classify_bump].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Bump {
+    /// Both versions compare as equal.
+    Equal,
+
+    /// The new version is older than the old one.
+    Downgrade,
+
+    /// The `epoch` was increased.
+    Epoch,
+
+    /// The `upstream_version` was increased, such as a new upstream
+    /// release.
+    Upstream,
+
+    /// Only the `debian_revision` was increased, such as a new Debian
+    /// upload (or a stable point release or security update) of the same
+    /// upstream release.
+    DebianRevision,
+
+    /// Only a binary-only rebuild (binNMU) suffix, such as `+b1`, was added
+    /// or increased, so both versions were built from the same source.
+    BinNMU,
+}
+
+/// Strip a trailing binNMU suffix (`+bN`) from a version part.
+fn strip_binnmu(part: &str) -> &str {
+    let Some((head, tail)) = part.rsplit_once("+b") else {
+        return part;
+    };
+    if !tail.is_empty() && tail.chars().all(|ch| ch.is_ascii_digit()) {
+        head
+    } else {
+        part
+    }
+}
+
+/// Return the `upstream_version` and `debian_revision` of the source
+/// package a [Version] was built from. The binNMU suffix is on the
+/// `debian_revision`, or on the `upstream_version` for a native package.
+fn source_parts(version: &Version) -> (&str, Option<&str>) {
+    match version.debian_revision() {
+        Some(revision) => (version.upstream_version(), Some(strip_binnmu(revision))),
+        None => (strip_binnmu(version.upstream_version()), None),
+    }
+}
+
+/// Determine what kind of change moving from the `old` [Version] to the
+/// `new` [Version] is, by comparing each component in turn from the most
+/// significant (the `epoch`) to the least significant (a binNMU suffix).
+///
+/// ```
+/// use deb::version::{self, Bump, Version};
+///
+/// let old: Version = "2.10-3".parse().unwrap();
+/// let new: Version = "2.10-3+deb12u1".parse().unwrap();
+/// assert_eq!(Bump::DebianRevision, version::classify_bump(&old, &new));
+/// ```
+pub fn classify_bump(old: &Version, new: &Version) -> Bump {
+    match old.cmp(new) {
+        Ordering::Equal => return Bump::Equal,
+        Ordering::Greater => return Bump::Downgrade,
+        Ordering::Less => {}
+    }
+
+    if old.epoch().unwrap_or(0) != new.epoch().unwrap_or(0) {
+        return Bump::Epoch;
+    }
+
+    let (old_upstream, old_revision) = source_parts(old);
+    let (new_upstream, new_revision) = source_parts(new);
+
+    if compare_version_str(old_upstream, new_upstream) != Ordering::Equal {
+        return Bump::Upstream;
+    }
+
+    if compare_version_str(old_revision.unwrap_or("0"), new_revision.unwrap_or("0"))
+        == Ordering::Equal
+    {
+        return Bump::BinNMU;
+    }
+
+    Bump::DebianRevision
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! check_classify_bump {
+        ($name:ident, $old:expr, $new:expr, $bump:expr) => {
+            #[test]
+            fn $name() {
+                let old: Version = $old.parse().unwrap();
+                let new: Version = $new.parse().unwrap();
+                assert_eq!($bump, classify_bump(&old, &new));
+            }
+        };
+    }
+
+    check_classify_bump!(equal, "1.0-1", "1.0-1", Bump::Equal);
+    check_classify_bump!(equal_epoch, "0:1.0-1", "1.0-1", Bump::Equal);
+    check_classify_bump!(downgrade, "1.0-2", "1.0-1", Bump::Downgrade);
+    check_classify_bump!(epoch, "1.0-1", "1:0.9-1", Bump::Epoch);
+    check_classify_bump!(upstream, "1.0-1", "1.1-1", Bump::Upstream);
+    check_classify_bump!(upstream_native, "1.0", "1.1", Bump::Upstream);
+    check_classify_bump!(debian_revision, "1.0-1", "1.0-2", Bump::DebianRevision);
+    check_classify_bump!(security, "1.0-1", "1.0-1+deb12u1", Bump::DebianRevision);
+    check_classify_bump!(binnmu, "1.0-1", "1.0-1+b1", Bump::BinNMU);
+    check_classify_bump!(binnmu_again, "1.0-1+b1", "1.0-1+b2", Bump::BinNMU);
+    check_classify_bump!(binnmu_native, "1.0", "1.0+b1", Bump::BinNMU);
+    check_classify_bump!(binnmu_dropped, "1.0-1+b1", "1.0-2", Bump::DebianRevision);
+    check_classify_bump!(not_binnmu, "1.0-1", "1.0-1+bpo1", Bump::DebianRevision);
+}
+
+// vim: foldmethod=marker
@@ -51,6 +51,103 @@ impl Ord for Version {
     }
 }
 
+/// Compare two version strings the way `dpkg --compare-versions` does,
+/// returning `-1` if `a` is older than `b`, `0` if they're equal, and `1`
+/// if `a` is newer than `b`.
+///
+/// This is the same comparison as [Version]'s [Ord] implementation, but
+/// returns the C-style integer that scripting or FFI callers expect.
+///
+/// ```
+/// use deb::version::dpkg_cmp;
+///
+/// assert_eq!(-1, dpkg_cmp("1.0-1", "1.0-2").unwrap());
+/// assert_eq!(0, dpkg_cmp("1.0", "0:1.0-0").unwrap());
+/// assert_eq!(1, dpkg_cmp("1:0.1", "2.0").unwrap());
+/// ```
+pub fn dpkg_cmp(a: &str, b: &str) -> Result<i8, Error> {
+    let a: Version = a.parse()?;
+    let b: Version = b.parse()?;
+    Ok(match a.cmp(&b) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    })
+}
+
+/// Single token of a [Version], as split up by the comparison algorithm,
+/// returned by [Version::debug_tokens].
+///
+/// Each part of a [Version] is split into alternating runs of non-digits and
+/// digits (always starting with a non-digit run, which may be empty), and
+/// the runs of two [Version]s are compared pairwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VersionToken {
+    /// Run of non-digit characters, compared character by character, with
+    /// letters sorting before non-letters.
+    NonNumeric {
+        /// Characters in the run, which may be empty.
+        text: String,
+
+        /// If true, the run contains a `~`, which sorts before anything,
+        /// even the end of a run. This is why `1.0~rc1` is older than
+        /// `1.0`.
+        tilde: bool,
+    },
+
+    /// Run of digits, compared by numeric value.
+    Numeric(String),
+
+    /// Boundary between the tokens of the `upstream_version` and the
+    /// `debian_revision`.
+    RevisionSeparator,
+}
+
+impl From<VersionComponent> for VersionToken {
+    fn from(component: VersionComponent) -> Self {
+        match component {
+            VersionComponent::String(text) => VersionToken::NonNumeric {
+                tilde: text.contains('~'),
+                text,
+            },
+            VersionComponent::Number(number) => VersionToken::Numeric(number),
+        }
+    }
+}
+
+impl Version {
+    /// Return the tokens the `upstream_version` and `debian_revision` of
+    /// this [Version] are split into when comparing it with another
+    /// [Version], separated by a [VersionToken::RevisionSeparator]. This is
+    /// meant to help understand a surprising comparison.
+    ///
+    /// A missing `debian_revision` is compared as if it were `0`, so it's
+    /// tokenized that way here too. The `epoch` is compared as a plain
+    /// number before any of these tokens, so it's left out.
+    ///
+    /// ```
+    /// use deb::version::{Version, VersionToken};
+    ///
+    /// let v: Version = "1.0~rc1-1".parse().unwrap();
+    /// assert!(v.debug_tokens().contains(&VersionToken::NonNumeric {
+    ///     text: "~rc".to_owned(),
+    ///     tilde: true,
+    /// }));
+    /// ```
+    pub fn debug_tokens(&self) -> Vec<VersionToken> {
+        VersionCompareIterator::new(self.upstream_version())
+            .flatten()
+            .map(VersionToken::from)
+            .chain(std::iter::once(VersionToken::RevisionSeparator))
+            .chain(
+                VersionCompareIterator::new(self.debian_revision().unwrap_or("0"))
+                    .flatten()
+                    .map(VersionToken::from),
+            )
+            .collect()
+    }
+}
+
 /// Used internally to parse dpkg versions
 struct VersionCompareIterator<'version> {
     _version: &'version str,
@@ -127,7 +224,7 @@ fn compare_version_char(left: char, right: char) -> Ordering {
     version_char_to_num(left).cmp(&version_char_to_num(right))
 }
 
-fn compare_version_str(left: &str, right: &str) -> Ordering {
+pub(super) fn compare_version_str(left: &str, right: &str) -> Ordering {
     let left_it = VersionCompareIterator::new(left)
         .flatten()
         .collect::<Vec<_>>()
@@ -289,6 +386,61 @@ mod test {
         };
     }
 
+    #[test]
+    fn check_debug_tokens() {
+        fn non_numeric(text: &str) -> VersionToken {
+            VersionToken::NonNumeric {
+                text: text.to_owned(),
+                tilde: text.contains('~'),
+            }
+        }
+        fn numeric(number: &str) -> VersionToken {
+            VersionToken::Numeric(number.to_owned())
+        }
+
+        let v: Version = "1.0~rc1".parse().unwrap();
+        assert_eq!(
+            vec![
+                non_numeric(""),
+                numeric("1"),
+                non_numeric("."),
+                numeric("0"),
+                VersionToken::NonNumeric {
+                    text: "~rc".to_owned(),
+                    tilde: true,
+                },
+                numeric("1"),
+                non_numeric(""),
+                VersionToken::RevisionSeparator,
+                non_numeric(""),
+                numeric("0"),
+                non_numeric(""),
+            ],
+            v.debug_tokens()
+        );
+
+        let v: Version = "2:1.0+dfsg-3ubuntu1".parse().unwrap();
+        assert_eq!(
+            vec![
+                non_numeric(""),
+                numeric("1"),
+                non_numeric("."),
+                numeric("0"),
+                VersionToken::NonNumeric {
+                    text: "+dfsg".to_owned(),
+                    tilde: false,
+                },
+                VersionToken::RevisionSeparator,
+                non_numeric(""),
+                numeric("3"),
+                non_numeric("ubuntu"),
+                numeric("1"),
+                non_numeric(""),
+            ],
+            v.debug_tokens()
+        );
+    }
+
     #[test]
     fn check_collect() {
         let v: Version = "1.2.3foo+bar~1~".parse().unwrap();
@@ -312,6 +464,34 @@ mod test {
         );
     }
 
+    macro_rules! check_dpkg_cmp {
+        ($name:ident, $version1:expr, $version2:expr, $check:expr) => {
+            #[test]
+            fn $name() {
+                let v1: Version = $version1.parse().unwrap();
+                let v2: Version = $version2.parse().unwrap();
+                let expected = match v1.cmp(&v2) {
+                    Ordering::Less => -1,
+                    Ordering::Equal => 0,
+                    Ordering::Greater => 1,
+                };
+                assert_eq!($check, expected);
+                assert_eq!($check, dpkg_cmp($version1, $version2).unwrap());
+            }
+        };
+    }
+
+    check_dpkg_cmp!(dpkg_cmp_less, "1.0", "1.2", -1);
+    check_dpkg_cmp!(dpkg_cmp_equal, "1.0", "0:1.0", 0);
+    check_dpkg_cmp!(dpkg_cmp_greater, "1:0.1", "2.0", 1);
+    check_dpkg_cmp!(dpkg_cmp_tilde, "1.0~rc1", "1.0", -1);
+
+    #[test]
+    fn dpkg_cmp_invalid() {
+        assert!(dpkg_cmp("", "1.0").is_err());
+        assert!(dpkg_cmp("1.0", "a:1").is_err());
+    }
+
     check_cmp!(cmp_simple_eq, "1.0", "1.0", Ordering::Equal);
     check_cmp!(cmp_simple_l, "1.0", "1.2", Ordering::Less);
     check_cmp!(cmp_simple_g, "1.2", "1.0", Ordering::Greater);
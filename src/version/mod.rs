@@ -48,11 +48,14 @@
 //! This feature will enable derives or explicit implementations of
 //! [serde::Deserialize] and [serde::Serialize] for types in this module.
 
+mod bump;
 mod compare;
 mod tests_dpkg;
 #[allow(clippy::module_inception)]
 mod version;
 
+pub use bump::{Bump, classify_bump};
+pub use compare::{VersionToken, dpkg_cmp};
 pub use version::{Error, Version};
 
 // vim: foldmethod=marker
@@ -9,7 +9,7 @@
 
 #[cfg(test)]
 mod test {
-    use crate::version::Version;
+    use crate::version::{Version, dpkg_cmp};
     use std::cmp::Ordering;
 
     macro_rules! dpkg_tests {
@@ -34,6 +34,8 @@ mod test {
                     "{} should be {:?} then {}, but is reported as {:?}",
                     v1, check, v2, cmp,
                 );
+
+                assert_eq!($check, dpkg_cmp($version1, $version2).unwrap());
             }
             )*
         };
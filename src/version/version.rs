@@ -109,9 +109,91 @@ pub enum Error {
 
     /// The `debian_revision` contains chars which are not permitted.
     InvalidDebianRevision,
+
+    /// The Version string is longer than the maximum length allowed by
+    /// [Version::MAX_LENGTH] or [Version::parse_with_max_length].
+    TooLong,
 }
 
 impl Version {
+    /// Default maximum length (in bytes) of a [Version] string accepted by
+    /// [Version::from_str]. Real-world versions are well under this, even
+    /// for snapshots with long VCS identifiers, so anything longer is
+    /// rejected with [Error::TooLong] rather than spending time on
+    /// pathological (or hostile) input.
+    ///
+    /// Use [Version::parse_with_max_length] to parse with a different limit.
+    pub const MAX_LENGTH: usize = 256;
+
+    /// Parse a [Version] string, returning [Error::TooLong] if the input
+    /// is longer than `max_length` bytes once leading and trailing
+    /// whitespace has been trimmed. [Version::from_str] calls this with
+    /// [Version::MAX_LENGTH].
+    pub fn parse_with_max_length(ver: &str, max_length: usize) -> Result<Self, Error> {
+        let ver = ver.trim();
+        if ver.len() > max_length {
+            return Err(Error::TooLong);
+        }
+        Self::parse_unbounded(ver)
+    }
+
+    /// Parse an already trimmed [Version] string, without any limit on
+    /// its length.
+    fn parse_unbounded(mut ver: &str) -> Result<Self, Error> {
+        let mut ret: Self = Default::default();
+
+        match ver.splitn(2, ':').collect::<Vec<_>>()[..] {
+            [version] => {
+                ver = version;
+            }
+            [epoch, version] => {
+                let epoch = epoch.parse().map_err(|_| Error::InvalidEpoch)?;
+                // i32 INT_MAX is a dpkg constraint.
+                if epoch > (i32::MAX as u64) {
+                    return Err(Error::InvalidEpoch);
+                }
+                ret.epoch = Some(epoch);
+                ver = version;
+            }
+            _ => {
+                return Err(Error::Malformed);
+            }
+        }
+
+        if ver.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        match ver.rsplitn(2, '-').collect::<Vec<_>>()[..] {
+            [upstream_version] => {
+                ret.upstream_version = upstream_version.to_owned();
+            }
+            [debian_revision, upstream_version] => {
+                if debian_revision.is_empty() {
+                    return Err(Error::NoDebianRevision);
+                }
+
+                if upstream_version.is_empty() {
+                    return Err(Error::NoUpstreamVersion);
+                }
+
+                ret.upstream_version = upstream_version.to_owned();
+                ret.debian_revision = Some(debian_revision.to_owned());
+            }
+            _ => {
+                return Err(Error::Malformed);
+            }
+        }
+
+        if ret.upstream_version.is_empty() {
+            return Err(Error::NoUpstreamVersion);
+        }
+
+        ret.check()?;
+
+        Ok(ret)
+    }
+
     /// Create a new Version, and verify that the constructed Version is
     /// valid from parts.
     pub fn from_parts(
@@ -145,6 +227,47 @@ impl Version {
         self.debian_revision.as_deref()
     }
 
+    /// Return the `epoch`, `upstream_version` and `debian_revision` of the
+    /// [Version] all at once, the same as calling [Version::epoch],
+    /// [Version::upstream_version] and [Version::debian_revision].
+    ///
+    /// ```
+    /// use deb::version::Version;
+    ///
+    /// let v: Version = "1:1.0-1".parse().unwrap();
+    /// let (epoch, upstream_version, debian_revision) = v.parts();
+    /// assert_eq!(Some(1), epoch);
+    /// assert_eq!("1.0", upstream_version);
+    /// assert_eq!(Some("1"), debian_revision);
+    /// ```
+    pub fn parts(&self) -> (Option<u64>, &str, Option<&str>) {
+        (
+            self.epoch(),
+            self.upstream_version(),
+            self.debian_revision(),
+        )
+    }
+
+    /// Return this [Version] with any leading zeros removed from the
+    /// `epoch`, so `01:1.0` is written out as `1:1.0`. The
+    /// `upstream_version` and `debian_revision` are never changed, since
+    /// leading zeros are significant there (`1.01` is not `1.1`).
+    ///
+    /// The `epoch` is parsed into a number, so leading zeros are already
+    /// gone from any [Version], and this returns an identical copy. It's
+    /// kept as an explicit way to get the cleaned-up form when tidying up
+    /// version strings for display.
+    ///
+    /// ```
+    /// use deb::version::Version;
+    ///
+    /// let v: Version = "01:1.01-01".parse().unwrap();
+    /// assert_eq!("1:1.01-01", v.normalize_epoch().to_string());
+    /// ```
+    pub fn normalize_epoch(&self) -> Self {
+        self.clone()
+    }
+
     /// Check that the version is permissible.
     fn check(&self) -> Result<(), Error> {
         if let Some(ch) = self.upstream_version.chars().next()
@@ -194,60 +317,8 @@ impl Version {
 impl FromStr for Version {
     type Err = Error;
 
-    fn from_str(mut ver: &str) -> Result<Self, Error> {
-        ver = ver.trim();
-        let mut ret: Self = Default::default();
-
-        match ver.splitn(2, ':').collect::<Vec<_>>()[..] {
-            [version] => {
-                ver = version;
-            }
-            [epoch, version] => {
-                let epoch = epoch.parse().map_err(|_| Error::InvalidEpoch)?;
-                // i32 INT_MAX is a dpkg constraint.
-                if epoch > (i32::MAX as u64) {
-                    return Err(Error::InvalidEpoch);
-                }
-                ret.epoch = Some(epoch);
-                ver = version;
-            }
-            _ => {
-                return Err(Error::Malformed);
-            }
-        }
-
-        if ver.is_empty() {
-            return Err(Error::Empty);
-        }
-
-        match ver.rsplitn(2, '-').collect::<Vec<_>>()[..] {
-            [upstream_version] => {
-                ret.upstream_version = upstream_version.to_owned();
-            }
-            [debian_revision, upstream_version] => {
-                if debian_revision.is_empty() {
-                    return Err(Error::NoDebianRevision);
-                }
-
-                if upstream_version.is_empty() {
-                    return Err(Error::NoUpstreamVersion);
-                }
-
-                ret.upstream_version = upstream_version.to_owned();
-                ret.debian_revision = Some(debian_revision.to_owned());
-            }
-            _ => {
-                return Err(Error::Malformed);
-            }
-        }
-
-        if ret.upstream_version.is_empty() {
-            return Err(Error::NoUpstreamVersion);
-        }
-
-        ret.check()?;
-
-        Ok(ret)
+    fn from_str(ver: &str) -> Result<Self, Error> {
+        Self::parse_with_max_length(ver, Self::MAX_LENGTH)
     }
 }
 
@@ -499,6 +570,55 @@ mod test {
         );
     }
 
+    #[test]
+    fn check_max_length() {
+        let ver = format!("100:{}", "1".repeat(1024 * 1024));
+        let start = std::time::Instant::now();
+        assert_eq!(Err(Error::TooLong), ver.parse::<Version>());
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+
+        let ver = "1".repeat(Version::MAX_LENGTH);
+        assert!(ver.parse::<Version>().is_ok());
+        let ver = "1".repeat(Version::MAX_LENGTH + 1);
+        assert_eq!(Err(Error::TooLong), ver.parse::<Version>());
+
+        // surrounding whitespace doesn't count towards the length.
+        let ver = format!("  {}\n", "1".repeat(Version::MAX_LENGTH));
+        assert!(ver.parse::<Version>().is_ok());
+    }
+
+    #[test]
+    fn check_parse_with_max_length() {
+        assert_eq!(
+            Err(Error::TooLong),
+            Version::parse_with_max_length("1.0-1", 4)
+        );
+        assert!(Version::parse_with_max_length("1.0-1", 5).is_ok());
+        assert!(Version::parse_with_max_length(" 1.0-1 ", 5).is_ok());
+
+        let ver = "1".repeat(Version::MAX_LENGTH * 2);
+        assert!(Version::parse_with_max_length(&ver, usize::MAX).is_ok());
+    }
+
+    // the epoch is parsed into a number, so leading zeros are dropped, but
+    // the upstream_version and debian_revision are kept as written.
+    macro_rules! check_normalize_epoch {
+        ($name:ident, $version:expr, $normalized:expr) => {
+            #[test]
+            fn $name() {
+                let v: Version = $version.parse().unwrap();
+                assert_eq!($normalized, v.normalize_epoch().to_string());
+                assert_eq!($normalized, v.to_string());
+                assert_eq!(v, v.normalize_epoch());
+            }
+        };
+    }
+
+    check_normalize_epoch!(normalize_epoch, "01:1.0", "1:1.0");
+    check_normalize_epoch!(normalize_epoch_zero, "000:1.0", "0:1.0");
+    check_normalize_epoch!(normalize_epoch_upstream, "1.01", "1.01");
+    check_normalize_epoch!(normalize_epoch_revision, "001:01.01-01", "1:01.01-01");
+
     check_fuzz_regression!(
         long_number,
         "100:222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222221~~~~~~~~~~~~~~~~~1~1~0"
@@ -131,6 +131,14 @@ test_good_archive_release_async!(
     async_fixtures_unsigned_archive_release_stable_release_good,
     include_bytes!("fixtures/unsigned/archive/release/stable-release.good")
 );
+test_good_archive_release!(
+    fixtures_unsigned_archive_release_bookworm_updates_good,
+    include_bytes!("fixtures/unsigned/archive/release/bookworm-updates.good")
+);
+test_good_archive_release_async!(
+    async_fixtures_unsigned_archive_release_bookworm_updates_good,
+    include_bytes!("fixtures/unsigned/archive/release/bookworm-updates.good")
+);
 test_bad_archive_release!(
     fixtures_unsigned_archive_release_stable_release_bad,
     include_bytes!("fixtures/unsigned/archive/release/stable-release.bad")
@@ -139,6 +147,14 @@ test_bad_archive_release_async!(
     async_fixtures_unsigned_archive_release_stable_release_bad,
     include_bytes!("fixtures/unsigned/archive/release/stable-release.bad")
 );
+test_good_archive_package!(
+    fixtures_unsigned_archive_package_bookworm_main_amd64_head_good,
+    include_bytes!("fixtures/unsigned/archive/package/bookworm-main-amd64-head.good")
+);
+test_good_archive_package_async!(
+    async_fixtures_unsigned_archive_package_bookworm_main_amd64_head_good,
+    include_bytes!("fixtures/unsigned/archive/package/bookworm-main-amd64-head.good")
+);
 test_good_archive_package!(
     fixtures_unsigned_archive_package_small_good,
     include_bytes!("fixtures/unsigned/archive/package/small.good")
@@ -310,11 +310,11 @@ macro_rules! test_good_archive_package {
         fn $name() {
             use std::io::{BufReader, Cursor};
             let mut file = BufReader::new(Cursor::new($bytes));
-            for package in
+            let packages =
                 deb::control::de::from_reader_iter::<deb::control::archive::Package, _>(&mut file)
-            {
-                let _package = package.unwrap();
-            }
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+            assert!(!packages.is_empty());
         }
     };
 }